@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+use grou_num::constants::pi_digits;
 use grou_num::grou::Grou;
 
 fn grou_create_clone(c: &mut Criterion) {
@@ -10,6 +11,14 @@ fn grou_create_clone(c: &mut Criterion) {
     })));
 }
 
+fn grou_create_clone_small(c: &mut Criterion) {
+    c.bench_function("create-u64", |b| b.iter(|| {
+        let x = Grou::from(black_box(100u64));
+        let y = x.clone();
+        black_box(x == y);
+    }));
+}
+
 fn grou_add(c: &mut Criterion) {
     let x = black_box(Grou::from(vec![1,2,3,4,5]));
     let y = black_box(x.clone());
@@ -64,6 +73,12 @@ fn grou_fibonacci_100k(c: &mut Criterion) {
     });
 }
 
+fn grou_fibonacci_fast_100k(c: &mut Criterion) {
+    c.bench_function("fibonacci-fast-doubling-100k", |b| {
+        b.iter(|| Grou::fibonacci(black_box(100_000)));
+    });
+}
+
 fn partial_eq_len_10(c : &mut Criterion) {
     let x = black_box(Grou::from(vec![1,2,3,4,5,6,7,8,9,10]));
     let y = Grou::from(vec![0,2,3,4,5,6,7,8,9,10]);
@@ -122,6 +137,7 @@ fn sub_len_500(c : &mut Criterion) {
 
 criterion_group!(grou_addition, 
     grou_create_clone,
+    grou_create_clone_small,
     grou_add,
     grou_add_assign,
     grou_verylarge_addition,
@@ -131,6 +147,7 @@ criterion_group!(grou_fib,
     grou_fibonacci_1000,
     grou_fibonacci_5000,
     grou_fibonacci_100k,
+    grou_fibonacci_fast_100k,
 );
 
 criterion_group!(grou_partial_eq_sub,
@@ -155,5 +172,28 @@ fn mul_karatsuba_len500(c : &mut Criterion) {
     });
 }
 
-criterion_group!(mul_karatsuba, mul_karatsuba_len50, mul_karatsuba_len500);
-criterion_main!(grou_addition, grou_fib, grou_partial_eq_sub, mul_karatsuba);
\ No newline at end of file
+fn mul_karatsuba_len5000(c : &mut Criterion) {
+    let (v, w) = generate_grou_pair(5000);
+    c.bench_function("mul-karatsuba-len5000", |b| {
+        b.iter(|| w.karatsuba_mul(&v));
+    });
+}
+
+fn mul_len5000(c : &mut Criterion) {
+    let (v, w) = generate_grou_pair(5000);
+    c.bench_function("mul-len5000", |b| {
+        b.iter(|| &w * &v);
+    });
+}
+
+criterion_group!(mul_karatsuba, mul_karatsuba_len50, mul_karatsuba_len500, mul_karatsuba_len5000, mul_len5000);
+
+// Benchmarks for the mathematical constants
+fn pi_10k_digits(c : &mut Criterion) {
+    c.bench_function("pi-10k-digits", |b| {
+        b.iter(|| pi_digits(black_box(10_000)));
+    });
+}
+
+criterion_group!(constants, pi_10k_digits);
+criterion_main!(grou_addition, grou_fib, grou_partial_eq_sub, mul_karatsuba, constants);
\ No newline at end of file
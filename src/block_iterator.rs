@@ -1,53 +1,101 @@
-use super::grou::{Grou, GrouSubset};
+use super::grou::{AsLimbs, GrouSubset};
 
+/// Iterates over consecutive blocks of block_length limbs of a number,
+/// from the least significant one up. The last block may be shorter, and
+/// an empty number, or a block_length of 0, gives no blocks.
+#[derive(Clone, Debug)]
 pub struct BlockIterator<'iter> {
-    number: &'iter Grou,
+    data: &'iter [u64],
     block_length: usize,
-    current_index: usize,
+    // Indices of the next blocks to return from the front and the back.
+    front: usize,
+    back: usize,
 }
 
 impl<'iter> BlockIterator<'iter> {
-    pub fn new(number: &'iter Grou, block_length: usize) -> BlockIterator {
+    pub fn new<T: AsLimbs + ?Sized>(number: &'iter T, block_length: usize) -> BlockIterator<'iter> {
+        let data = number.limbs();
+        let back = if block_length == 0 {
+            0
+        } else {
+            data.len().div_ceil(block_length)
+        };
         BlockIterator {
-            number,
-            block_length, 
-            current_index: 0
+            data,
+            block_length,
+            front: 0,
+            back,
         }
     }
+
+    /// The number of limbs in each block but possibly the last.
     #[inline(always)]
-    pub fn len(&self) -> usize {
+    pub fn block_length(&self) -> usize {
         self.block_length
     }
+
+    fn block(&self, index: usize) -> GrouSubset<'iter> {
+        let start = index * self.block_length;
+        let end = std::cmp::min(start + self.block_length, self.data.len());
+        GrouSubset {
+            data: &self.data[start..end],
+        }
+    }
 }
 
 impl<'iter> Iterator for BlockIterator<'iter> {
     type Item = GrouSubset<'iter>;
 
     fn next(&mut self) -> Option<GrouSubset<'iter>> {
-        if self.block_length == 0 {
+        if self.front == self.back {
             None
         } else {
-            let ret_val = Some(self.number.split_off_block(self.current_index, self.block_length));
-            if self.current_index + self.block_length >= self.number.len() {
-                // Indicates termination. All further elements will return None.
-                self.block_length = 0; 
-            }
+            self.front += 1;
+            Some(self.block(self.front - 1))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
 
-            ret_val
+impl<'iter> DoubleEndedIterator for BlockIterator<'iter> {
+    fn next_back(&mut self) -> Option<GrouSubset<'iter>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.block(self.back))
         }
     }
 }
 
+impl ExactSizeIterator for BlockIterator<'_> {}
+
+impl std::iter::FusedIterator for BlockIterator<'_> {}
+
 impl<'iter> BlockIterator<'iter> {
     pub fn unwrap_next(&mut self) -> GrouSubset<'iter> {
         self.next().unwrap()
     }
 }
 
-pub fn calculate_block_length(nblocks:usize, g1: &Grou, g2:&Grou) -> usize {
-    let max_length = std::cmp::max(g1.len(), g2.len());
+/// Returns the block length that splits the longer of g1 and g2 into
+/// nblocks blocks. Splitting into 0 blocks gives a length of 0, for which
+/// BlockIterator returns no blocks.
+pub fn calculate_block_length<A, B>(nblocks: usize, g1: &A, g2: &B) -> usize
+where
+    A: AsLimbs + ?Sized,
+    B: AsLimbs + ?Sized,
+{
+    if nblocks == 0 {
+        return 0;
+    }
+    let max_length = std::cmp::max(g1.limbs().len(), g2.limbs().len());
 
     // Integer division + 1 if the division had a remainder, is equivalent to
     // ceil(max_length / nblocks);
-    max_length / nblocks + ((max_length % nblocks != 0) as usize)
-}
\ No newline at end of file
+    max_length / nblocks + (!max_length.is_multiple_of(nblocks) as usize)
+}
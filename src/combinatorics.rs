@@ -0,0 +1,86 @@
+use super::grou::Grou;
+use super::sieve::sieve_primes;
+
+impl Grou {
+    /// Returns n! = 1 * 2 * ... * n.
+    pub fn factorial(n: u64) -> Grou {
+        product_tree(1..=n)
+    }
+
+    /// Returns n!! = n * (n - 2) * (n - 4) * ..., down to 1 or 2.
+    pub fn double_factorial(n: u64) -> Grou {
+        product_tree((1..=n).rev().step_by(2))
+    }
+
+    /// Returns the product of all primes <= n.
+    pub fn primorial(n: u64) -> Grou {
+        product_tree(sieve_primes(n as usize).into_iter())
+    }
+
+    /// Returns the binomial coefficient n choose k, which is 0 when k > n.
+    ///
+    /// When k is small next to n, the falling product n (n - 1) ... (n - k + 1)
+    /// is divided by k!, so the cost depends on k rather than on n.
+    /// Otherwise the exponent of each prime p in n! / (k! (n - k)!) is
+    /// counted with Legendre's formula, and the prime powers are multiplied
+    /// together with a product tree. This skips the large intermediate
+    /// factorials, at the cost of sieving the primes up to n.
+    pub fn binomial(n: u64, k: u64) -> Grou {
+        if k > n {
+            return Grou::from(0);
+        }
+        let k = std::cmp::min(k, n - k);
+        if k == 0 {
+            return Grou::from(1);
+        }
+        if k <= n / 16 {
+            return product_tree(n - k + 1..=n) / Grou::factorial(k);
+        }
+
+        let factors = sieve_primes(n as usize).into_iter().flat_map(move |p| {
+            let mut exponent = 0;
+            let mut power = p;
+            loop {
+                exponent += n / power - k / power - (n - k) / power;
+                match power.checked_mul(p) {
+                    Some(next) if next <= n => power = next,
+                    _ => break,
+                }
+            }
+            std::iter::repeat_n(p, exponent as usize)
+        });
+        product_tree(factors)
+    }
+}
+
+// Multiplies the factors together. Consecutive factors are first packed
+// into single blocks for as long as their product fits, then the blocks are
+// multiplied as a balanced binary tree, so that the large multiplications
+// have operands of similar size.
+fn product_tree(factors: impl Iterator<Item = u64>) -> Grou {
+    let mut blocks: Vec<Grou> = Vec::new();
+    let mut current = 1u64;
+    for factor in factors {
+        match current.checked_mul(factor) {
+            Some(product) => current = product,
+            None => {
+                blocks.push(Grou::from(current));
+                current = factor;
+            }
+        }
+    }
+    blocks.push(Grou::from(current));
+
+    multiply_balanced(&blocks)
+}
+
+fn multiply_balanced(values: &[Grou]) -> Grou {
+    match values.len() {
+        0 => Grou::from(1),
+        1 => values[0].clone(),
+        length => {
+            let (low, high) = values.split_at(length / 2);
+            multiply_balanced(low) * multiply_balanced(high)
+        }
+    }
+}
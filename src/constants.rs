@@ -0,0 +1,132 @@
+use super::grou::Grou;
+use super::signed::GrouCoeff;
+
+// Extra digits computed beyond the requested ones, so that the error of the
+// truncated series and of the integer square root does not reach the last
+// printed digit. More are computed when these do not settle it.
+const GUARD_DIGITS: usize = 10;
+
+// A bound on the error of the scaled values below, in units of their last
+// digit. The series are summed past that digit, and the square root and the
+// final division each lose less than one unit.
+const SCALED_ERROR: u64 = 10;
+
+/// Returns pi with n digits after the decimal point, truncated, as in
+/// "3.14159" for n = 5.
+///
+/// This uses the Chudnovsky series
+///     1 / pi = 12 / 640320^(3/2) * sum (-1)^k (6k)! (13591409 + 545140134 k)
+///                                      / ((3k)! (k!)^3 640320^(3k)),
+/// whose terms each add about 14 digits. The sum is evaluated exactly as a
+/// single fraction by binary splitting.
+pub fn pi_digits(n: usize) -> String {
+    truncated_digits(n, pi_scaled)
+}
+
+// Returns pi * 10^digits, to within SCALED_ERROR.
+fn pi_scaled(digits: usize) -> Grou {
+    let terms = (digits as u64) / 14 + 2;
+    let (_, q, t) = chudnovsky_split(0, terms);
+
+    // pi = 426880 sqrt(10005) Q / T
+    let ten_power = Grou::from(10).pow(digits as u32);
+    let root = (Grou::from(10005) * &ten_power * &ten_power).isqrt();
+    let numerator = Grou::from(426880) * root * q;
+    numerator / t.magnitude()
+}
+
+/// Returns e with n digits after the decimal point, truncated.
+///
+/// This sums the Taylor series e = sum 1 / k! by binary splitting, with
+/// enough terms that k! exceeds 10^n.
+pub fn e_digits(n: usize) -> String {
+    truncated_digits(n, e_scaled)
+}
+
+// Returns e * 10^digits, to within SCALED_ERROR.
+fn e_scaled(digits: usize) -> Grou {
+    // The first k with log10(k!) above the number of digits.
+    let mut terms = 1u64;
+    let mut log_factorial = 0.0f64;
+    while log_factorial <= digits as f64 + 1.0 {
+        terms += 1;
+        log_factorial += (terms as f64).log10();
+    }
+
+    // e = 1 + P / Q
+    let (p, q) = e_split(0, terms);
+    let ten_power = Grou::from(10).pow(digits as u32);
+    ((&q + p) * ten_power) / q
+}
+
+/// Returns the square root of 2 with n digits after the decimal point,
+/// truncated.
+pub fn sqrt2_digits(n: usize) -> String {
+    let ten_power = Grou::from(10).pow(n as u32);
+    let root = (Grou::from(2) * &ten_power * &ten_power).isqrt();
+    let mut s = crate::radix_convert::convert_to_decimal_string(&root);
+    if n > 0 {
+        s.insert(1, '.');
+    }
+    s
+}
+
+// Prints a constant x in [1, 10) with n digits after the point, truncated,
+// given scaled(digits) = x * 10^digits to within SCALED_ERROR. When the
+// guard digits are so close to all 0s or all 9s that the error could carry
+// into the printed digits, they do not tell which way to truncate, so the
+// value is computed again with twice as many.
+fn truncated_digits(n: usize, scaled: fn(usize) -> Grou) -> String {
+    let mut guard = GUARD_DIGITS;
+    loop {
+        let unit = Grou::from(10).pow(guard as u32);
+        let (truncated, rest) = scaled(n + guard).div_rem(&unit);
+        if rest >= Grou::from(SCALED_ERROR) && rest + SCALED_ERROR < unit {
+            let mut s = crate::radix_convert::convert_to_decimal_string(&truncated);
+            if n > 0 {
+                s.insert(1, '.');
+            }
+            return s;
+        }
+        guard *= 2;
+    }
+}
+
+// Binary splitting of the terms a..b of the Chudnovsky series, returning
+// (P, Q, T) with T / Q the partial sum scaled by the first term's ratio.
+// Splitting at the midpoint keeps the multiplications balanced.
+fn chudnovsky_split(a: u64, b: u64) -> (Grou, Grou, GrouCoeff) {
+    // 640320^3 / 24
+    const C3_OVER_24: u64 = 10_939_058_860_032_000;
+
+    if b - a == 1 {
+        let (p, q) = if a == 0 {
+            (Grou::from(1), Grou::from(1))
+        } else {
+            let p = Grou::from(6 * a - 5) * Grou::from(2 * a - 1) * Grou::from(6 * a - 1);
+            let q = Grou::from(a).pow(3) * Grou::from(C3_OVER_24);
+            (p, q)
+        };
+        let t = &p * (Grou::from(13591409) + Grou::from(545140134) * Grou::from(a));
+        let t = GrouCoeff::new(a % 2 == 1, t);
+        return (p, q, t);
+    }
+
+    let middle = (a + b) / 2;
+    let (p_left, q_left, t_left) = chudnovsky_split(a, middle);
+    let (p_right, q_right, t_right) = chudnovsky_split(middle, b);
+    let t = t_left * &q_right + t_right * &p_left;
+    (p_left * p_right, q_left * q_right, t)
+}
+
+// Binary splitting for sum over k in (a, b] of a! / k!, returning (P, Q)
+// with Q = b! / a! and P / Q the sum.
+fn e_split(a: u64, b: u64) -> (Grou, Grou) {
+    if b - a == 1 {
+        return (Grou::from(1), Grou::from(b));
+    }
+    let middle = (a + b) / 2;
+    let (p_left, q_left) = e_split(a, middle);
+    let (p_right, q_right) = e_split(middle, b);
+    (p_left * &q_right + p_right, q_left * q_right)
+}
@@ -0,0 +1,141 @@
+use super::grou::Grou;
+
+/// Returns the x in [0, m_0 * m_1 * ...) with x = residues[i] mod moduli[i]
+/// for every i.
+///
+/// Returns None if the moduli are not pairwise coprime, if one of them is
+/// 0, or if the slices have different lengths.
+pub fn crt(residues: &[Grou], moduli: &[Grou]) -> Option<Grou> {
+    CrtBasis::new(moduli)?.reconstruct(residues)
+}
+
+/// Precomputed data for Chinese remainder reconstruction with a fixed set of
+/// pairwise coprime moduli.
+///
+/// The moduli are kept in a product tree, whose levels hold the products
+/// of pairs from the level below. Reconstruction combines the residues up
+/// the tree, and reduction sends a number down the tree as a remainder
+/// tree, so both take a logarithmic number of rounds of balanced
+/// multiplications or divisions.
+#[derive(Clone, Debug)]
+pub struct CrtBasis {
+    // tree[0] holds the moduli, and the last level holds their product.
+    tree: Vec<Vec<Grou>>,
+    // ((M / m_i)^-1 mod m_i), where M is the product of all moduli.
+    inverses: Vec<Grou>,
+}
+
+impl CrtBasis {
+    /// Returns None if the moduli are not pairwise coprime, or if one of them
+    /// is 0.
+    pub fn new(moduli: &[Grou]) -> Option<CrtBasis> {
+        if moduli.iter().any(|m| m.is_zero()) {
+            return None;
+        }
+        let tree = product_tree(moduli.to_vec());
+        let product = &tree[tree.len() - 1][0];
+
+        // M mod m_i^2 = (M / m_i mod m_i) * m_i, and the squares' remainder
+        // tree gives all of those at once.
+        let squares: Vec<Grou> = moduli.iter().map(|m| m * m).collect();
+        let square_tree = product_tree(squares);
+        let cofactors = remainder_tree(product, &square_tree);
+
+        let mut inverses = Vec::with_capacity(moduli.len());
+        for (cofactor, m) in cofactors.iter().zip(moduli.iter()) {
+            inverses.push((cofactor / m).mod_inverse(m)?);
+        }
+        Some(CrtBasis { tree, inverses })
+    }
+
+    /// Returns the moduli the basis was built from.
+    pub fn moduli(&self) -> &[Grou] {
+        &self.tree[0]
+    }
+
+    /// Returns the product of the moduli.
+    pub fn modulus(&self) -> &Grou {
+        &self.tree[self.tree.len() - 1][0]
+    }
+
+    /// Returns the x in [0, modulus) with x = residues[i] mod moduli[i].
+    /// Returns None if the number of residues does not match.
+    pub fn reconstruct(&self, residues: &[Grou]) -> Option<Grou> {
+        if residues.len() != self.inverses.len() {
+            return None;
+        }
+        if residues.is_empty() {
+            return Some(Grou::from(0));
+        }
+
+        // Each leaf holds x_i = r_i (M / m_i)^-1 mod m_i. A node whose
+        // children hold (a, A) and (b, B) holds a * B + b * A, which keeps
+        // the invariant that each node's value is sum x_i (P / m_i) over its
+        // leaves, P being the node's product.
+        let mut values: Vec<Grou> = residues
+            .iter()
+            .zip(self.inverses.iter().zip(self.tree[0].iter()))
+            .map(|(r, (inverse, m))| (r % m * inverse) % m)
+            .collect();
+
+        for level in 0..self.tree.len() - 1 {
+            let products = &self.tree[level];
+            let mut next = Vec::with_capacity(values.len().div_ceil(2));
+            for (pair, product_pair) in values.chunks(2).zip(products.chunks(2)) {
+                if pair.len() == 2 {
+                    next.push(&pair[0] * &product_pair[1] + &pair[1] * &product_pair[0]);
+                } else {
+                    next.push(pair[0].clone());
+                }
+            }
+            values = next;
+        }
+
+        let mut result = &values[0] % self.modulus();
+        result.trim();
+        Some(result)
+    }
+
+    /// Returns x mod m_i for each modulus, using a remainder tree.
+    pub fn reduce(&self, x: &Grou) -> Vec<Grou> {
+        remainder_tree(x, &self.tree)
+    }
+}
+
+// Builds the levels of the product tree, from the leaves up to the root.
+// An empty set of leaves has product 1.
+fn product_tree(leaves: Vec<Grou>) -> Vec<Vec<Grou>> {
+    let mut tree = vec![leaves];
+    if tree[0].is_empty() {
+        tree.push(vec![Grou::from(1)]);
+    }
+    while tree[tree.len() - 1].len() > 1 {
+        let level = &tree[tree.len() - 1];
+        let next = level
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    &pair[0] * &pair[1]
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+        tree.push(next);
+    }
+    tree
+}
+
+// Returns x mod each leaf of the tree, reducing by each level on the way
+// down so the divisions stay balanced.
+fn remainder_tree(x: &Grou, tree: &[Vec<Grou>]) -> Vec<Grou> {
+    let mut remainders = vec![x % &tree[tree.len() - 1][0]];
+    for level in tree[..tree.len() - 1].iter().rev() {
+        remainders = level
+            .iter()
+            .enumerate()
+            .map(|(ind, m)| &remainders[ind / 2] % m)
+            .collect();
+    }
+    remainders
+}
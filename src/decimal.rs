@@ -0,0 +1,213 @@
+use super::grou::Grou;
+use super::rounding::RoundingMode;
+use super::signed::GrouCoeff;
+
+/// A decimal fixed-point number, mantissa / 10^scale.
+///
+/// The scale is the number of digits after the decimal point. It is kept
+/// as given rather than normalized, so 1.50 and 1.5 print differently, but
+/// they compare equal.
+#[derive(Clone, Debug)]
+pub struct GrouDecimal {
+    mantissa: GrouCoeff,
+    scale: u32,
+}
+
+fn power_of_ten(exponent: u32) -> Grou {
+    Grou::from(10).pow(exponent)
+}
+
+impl GrouDecimal {
+    pub fn new(mantissa: GrouCoeff, scale: u32) -> GrouDecimal {
+        GrouDecimal { mantissa, scale }
+    }
+
+    #[inline]
+    pub fn mantissa(&self) -> &GrouCoeff {
+        &self.mantissa
+    }
+
+    #[inline]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Returns the same value with the given scale. Increasing the scale is
+    /// exact, decreasing it rounds with the given mode.
+    pub fn with_scale(&self, scale: u32, mode: RoundingMode) -> GrouDecimal {
+        if scale >= self.scale {
+            let factor = power_of_ten(scale - self.scale);
+            return GrouDecimal::new(&self.mantissa * &factor, scale);
+        }
+        let divisor = power_of_ten(self.scale - scale);
+        let mantissa = divide_rounded(self.mantissa.clone(), &divisor, false, mode);
+        GrouDecimal::new(mantissa, scale)
+    }
+
+    /// Returns self / other, rounded to the given scale. Panics if other
+    /// is 0.
+    pub fn div_with_scale(
+        &self,
+        other: &GrouDecimal,
+        scale: u32,
+        mode: RoundingMode,
+    ) -> GrouDecimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        // self / other = (m_a / m_b) * 10^(s_b - s_a), and the result's
+        // mantissa is that times 10^scale.
+        let (negative, divisor) = other.mantissa.clone().into_parts();
+        let shift = scale as i64 + other.scale as i64 - self.scale as i64;
+        let (numerator, divisor) = if shift >= 0 {
+            (&self.mantissa * power_of_ten(shift as u32), divisor)
+        } else {
+            (
+                self.mantissa.clone(),
+                divisor * power_of_ten((-shift) as u32),
+            )
+        };
+        let mantissa = divide_rounded(numerator, &divisor, negative, mode);
+        GrouDecimal::new(mantissa, scale)
+    }
+
+    // Both mantissas, at the larger of the two scales.
+    fn aligned(&self, other: &GrouDecimal) -> (GrouCoeff, GrouCoeff, u32) {
+        let scale = std::cmp::max(self.scale, other.scale);
+        let lhs = &self.mantissa * power_of_ten(scale - self.scale);
+        let rhs = &other.mantissa * power_of_ten(scale - other.scale);
+        (lhs, rhs, scale)
+    }
+}
+
+// Returns num / divisor rounded with the mode, flipping the sign of the
+// result if negate is set.
+fn divide_rounded(num: GrouCoeff, divisor: &Grou, negate: bool, mode: RoundingMode) -> GrouCoeff {
+    let (negative, magnitude) = num.into_parts();
+    let negative = negative ^ negate;
+    let (quotient, remainder) = magnitude.div_rem(divisor);
+    let quotient = mode.round_quotient(quotient, &remainder, divisor, negative);
+    GrouCoeff::new(negative, quotient)
+}
+
+impl From<GrouCoeff> for GrouDecimal {
+    fn from(mantissa: GrouCoeff) -> GrouDecimal {
+        GrouDecimal::new(mantissa, 0)
+    }
+}
+
+impl From<Grou> for GrouDecimal {
+    fn from(mantissa: Grou) -> GrouDecimal {
+        GrouDecimal::new(GrouCoeff::from(mantissa), 0)
+    }
+}
+
+impl From<i64> for GrouDecimal {
+    fn from(mantissa: i64) -> GrouDecimal {
+        GrouDecimal::new(GrouCoeff::from(mantissa), 0)
+    }
+}
+
+/// Parses a decimal number such as "-12.340", keeping the number of digits
+/// after the point as the scale. Panics on invalid input.
+impl std::convert::From<&str> for GrouDecimal {
+    fn from(s: &str) -> GrouDecimal {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let is_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            panic!("Input format is invalid.")
+        }
+        let mut digits = String::from(whole);
+        digits.push_str(fraction);
+        let mantissa = GrouCoeff::new(negative, Grou::from(&digits[..]));
+        GrouDecimal::new(mantissa, fraction.len() as u32)
+    }
+}
+
+impl std::fmt::Display for GrouDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut digits = crate::radix_convert::convert_to_decimal_string(self.mantissa.magnitude());
+        let scale = self.scale as usize;
+        if scale > 0 {
+            if digits.len() <= scale {
+                let padding = "0".repeat(scale + 1 - digits.len());
+                digits.insert_str(0, &padding);
+            }
+            digits.insert(digits.len() - scale, '.');
+        }
+        f.pad_integral(!self.mantissa.is_negative(), "", &digits)
+    }
+}
+
+impl PartialEq for GrouDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs, rhs, _) = self.aligned(other);
+        lhs == rhs
+    }
+}
+
+impl Eq for GrouDecimal {}
+
+impl std::cmp::PartialOrd for GrouDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let (lhs, rhs, _) = self.aligned(other);
+        lhs.partial_cmp(&rhs)
+    }
+}
+
+impl std::ops::Neg for GrouDecimal {
+    type Output = GrouDecimal;
+    fn neg(self) -> GrouDecimal {
+        GrouDecimal::new(-self.mantissa, self.scale)
+    }
+}
+
+impl std::ops::Neg for &GrouDecimal {
+    type Output = GrouDecimal;
+    fn neg(self) -> GrouDecimal {
+        GrouDecimal::new(-&self.mantissa, self.scale)
+    }
+}
+
+// Sums keep the larger scale and products add the scales, so all three are
+// exact.
+macro_rules! impl_ops_decimal {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::ops::Add<$rhs> for $lhs {
+            type Output = GrouDecimal;
+            fn add(self, other: $rhs) -> GrouDecimal {
+                let (lhs, rhs, scale) = self.aligned(&other);
+                GrouDecimal::new(lhs + rhs, scale)
+            }
+        }
+
+        impl std::ops::Sub<$rhs> for $lhs {
+            type Output = GrouDecimal;
+            fn sub(self, other: $rhs) -> GrouDecimal {
+                let (lhs, rhs, scale) = self.aligned(&other);
+                GrouDecimal::new(lhs - rhs, scale)
+            }
+        }
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = GrouDecimal;
+            fn mul(self, other: $rhs) -> GrouDecimal {
+                GrouDecimal::new(&self.mantissa * &other.mantissa, self.scale + other.scale)
+            }
+        }
+    };
+}
+
+impl_ops_decimal!(GrouDecimal, GrouDecimal);
+impl_ops_decimal!(GrouDecimal, &GrouDecimal);
+impl_ops_decimal!(&GrouDecimal, GrouDecimal);
+impl_ops_decimal!(&GrouDecimal, &GrouDecimal);
@@ -0,0 +1,257 @@
+use super::grou::{AsLimbs, Grou};
+use super::limbs;
+use super::ops::{cmp_limbs, significant};
+
+impl Grou {
+    /// Divides by a single block, returning the quotient and the remainder.
+    pub fn div_rem_small(&self, rhs: u64) -> (Grou, u64) {
+        div_rem_1(&self.data, rhs)
+    }
+
+    /// Returns self mod rhs, for a single block rhs.
+    pub fn rem_small(&self, rhs: u64) -> u64 {
+        if rhs == 0 {
+            panic!("Division by zero");
+        }
+        let mut remainder = 0u64;
+        for val in self.data.iter().rev() {
+            let current = ((remainder as u128) << 64) | (*val as u128);
+            remainder = (current % rhs as u128) as u64;
+        }
+        remainder
+    }
+
+    /// Returns the quotient and the remainder of self / rhs.
+    /// Panics if rhs is 0.
+    pub fn div_rem<R: AsLimbs + ?Sized>(&self, rhs: &R) -> (Grou, Grou) {
+        div_rem_limbs(&self.data, rhs.limbs())
+    }
+}
+
+// Divides one number by another, given as blocks. Panics if the divisor
+// is 0.
+pub(crate) fn div_rem_limbs(dividend: &[u64], divisor: &[u64]) -> (Grou, Grou) {
+    let dividend = significant(dividend);
+    let divisor = significant(divisor);
+    if divisor.is_empty() {
+        panic!("Division by zero");
+    }
+
+    if cmp_limbs(dividend, divisor) == std::cmp::Ordering::Less {
+        let remainder = if dividend.is_empty() {
+            Grou::from(0)
+        } else {
+            Grou::from(dividend.to_vec())
+        };
+        return (Grou::from(0), remainder);
+    }
+
+    if divisor.len() == 1 {
+        let (q, r) = div_rem_1(dividend, divisor[0]);
+        return (q, Grou::from(r));
+    }
+
+    div_rem_knuth(dividend, divisor)
+}
+
+// Divides by a single block.
+fn div_rem_1(dividend: &[u64], rhs: u64) -> (Grou, u64) {
+    if rhs == 0 {
+        panic!("Division by zero");
+    }
+    let mut quotient = vec![0u64; std::cmp::max(dividend.len(), 1)];
+    let mut remainder = 0u64;
+    for (ind, val) in dividend.iter().enumerate().rev() {
+        let current = ((remainder as u128) << 64) | (*val as u128);
+        quotient[ind] = (current / rhs as u128) as u64;
+        remainder = (current % rhs as u128) as u64;
+    }
+
+    let mut q = Grou::from(quotient);
+    q.trim();
+    (q, remainder)
+}
+
+// Knuth's Algorithm D (TAOCP vol. 2, 4.3.1). Expects the divisor to have
+// at least two blocks, with a nonzero top block, and the dividend to be at
+// least as large as the divisor.
+fn div_rem_knuth(dividend: &[u64], divisor: &[u64]) -> (Grou, Grou) {
+    let n = divisor.len();
+    let m = dividend.len() - n;
+
+    // Normalize so that the top bit of the divisor is set. This makes the
+    // estimate for each quotient block off by at most 2.
+    let shift = divisor[n - 1].leading_zeros();
+    let v = normalize(divisor, shift, false);
+    let mut u = normalize(dividend, shift, true);
+
+    let mut quotient = vec![0u64; m + 1];
+    let v_top = v[n - 1] as u128;
+    let v_next = v[n - 2] as u128;
+
+    for j in (0..=m).rev() {
+        let numerator = ((u[j + n] as u128) << 64) | (u[j + n - 1] as u128);
+        let mut qhat = numerator / v_top;
+        let mut rhat = numerator % v_top;
+
+        while qhat > u64::MAX as u128
+            || qhat * v_next > ((rhat << 64) | u[j + n - 2] as u128)
+        {
+            qhat -= 1;
+            rhat += v_top;
+            if rhat > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        // Multiply and subtract qhat * v from u[j..j + n + 1].
+        let mul_borrow = limbs::submul_1(&mut u[j..j + n], &v, qhat as u64);
+        let (value, borrow) = u[j + n].overflowing_sub(mul_borrow);
+        u[j + n] = value;
+
+        // qhat was one too large: add v back.
+        if borrow {
+            qhat -= 1;
+            let carry = limbs::add_n_assign(&mut u[j..j + n], &v);
+            u[j + n] = u[j + n].wrapping_add(carry);
+        }
+
+        quotient[j] = qhat as u64;
+    }
+
+    // Unnormalize the remainder.
+    let mut remainder = vec![0u64; n];
+    for i in 0..n {
+        remainder[i] = if shift == 0 {
+            u[i]
+        } else {
+            (u[i] >> shift) | (u[i + 1] << (64 - shift))
+        };
+    }
+
+    let mut q = Grou::from(quotient);
+    q.trim();
+    let mut r = Grou::from(remainder);
+    r.trim();
+    (q, r)
+}
+
+// Shifts the blocks left by `shift` bits, with an extra high block if requested.
+fn normalize(data: &[u64], shift: u32, extra_block: bool) -> Vec<u64> {
+    let mut result = Vec::with_capacity(data.len() + 1);
+    let mut carry = 0u64;
+    for val in data.iter() {
+        if shift == 0 {
+            result.push(*val);
+        } else {
+            result.push((val << shift) | carry);
+            carry = val >> (64 - shift);
+        }
+    }
+    if extra_block {
+        result.push(carry);
+    }
+    result
+}
+
+// Reciprocals with a quotient of at most this many blocks are computed by
+// long division. Larger ones use Newton's iteration.
+const RECIPROCAL_THRESHOLD: usize = 32;
+
+// Returns floor(2^bits / d). Each step of Newton's iteration doubles the
+// precision of an estimate with two multiplications, so this costs a few
+// products of the quotient's size instead of a quadratic long division.
+// Panics if d is 0.
+pub(crate) fn reciprocal(d: &Grou, bits: usize) -> Grou {
+    let length = d.bit_length();
+    if length == 0 {
+        panic!("Division by zero");
+    }
+    if bits < length {
+        return Grou::from(0);
+    }
+    // The quotient has precision or precision + 1 bits.
+    let precision = bits - length;
+    let power = Grou::from(1).shift_left(bits);
+    if precision <= 64 * RECIPROCAL_THRESHOLD {
+        return power / d;
+    }
+
+    // An estimate to about half the precision, from the top bits of d only.
+    let half = precision / 2 + 64;
+    let dropped = length.saturating_sub(half + 64);
+    let shift = precision - half;
+    let estimate = reciprocal(&d.shift_right(dropped), bits - dropped - shift).shift_left(shift);
+
+    // One Newton step: x + x * (2^bits - d * x) / 2^bits.
+    let (below, error) = power.sub_with_sign(&(d * &estimate));
+    let correction = (&estimate * &error).shift_right(bits);
+    let mut x = if below {
+        estimate + correction
+    } else {
+        estimate - correction
+    };
+
+    // x is now within a few units of the result. Step it until
+    // 2^bits - d * x lies in [0, d).
+    let (mut below, mut rest) = power.sub_with_sign(&(d * &x));
+    while !below && !rest.is_zero() {
+        x -= Grou::from(1);
+        (below, rest) = d.sub_with_sign(&rest);
+    }
+    while rest >= *d {
+        x += 1u64;
+        rest -= d;
+    }
+    x
+}
+
+// Divides x by d, given inverse = reciprocal(d, bits) with x < 2^bits.
+// The quotient is estimated from the top blocks of x and of the inverse,
+// both rounded down, so the estimate is at most a few units low. This takes
+// two multiplications of the quotient's size instead of a long division.
+pub(crate) fn div_rem_with_reciprocal(
+    x: &Grou,
+    d: &Grou,
+    inverse: &Grou,
+    bits: usize,
+) -> (Grou, Grou) {
+    let x_length = 64 * x.len();
+    let d_length = 64 * (d.len() - 1);
+    if x_length <= d_length || x_length > bits {
+        return x.div_rem(d);
+    }
+    // Dropping the low d_length bits of x loses less than 1 from the
+    // estimate, as d >= 2^d_length, and dropping the low bits - x_length
+    // bits of the inverse loses less than 1 as well.
+    let x_top = x.shift_right(d_length);
+    let inverse_top = inverse.shift_right(bits - x_length);
+    let mut quotient = (&x_top * &inverse_top).shift_right(x_length - d_length);
+    let mut remainder = x - &(&quotient * d);
+    while remainder >= *d {
+        remainder -= d;
+        quotient += 1u64;
+    }
+    (quotient, remainder)
+}
+
+macro_rules! impl_div_rem_small {
+    ($lhs: ty) => {
+        impl std::ops::Div<u64> for $lhs {
+            type Output = Grou;
+            fn div(self, other: u64) -> Grou {
+                self.div_rem_small(other).0
+            }
+        }
+
+        impl std::ops::Rem<u64> for $lhs {
+            type Output = u64;
+            fn rem(self, other: u64) -> u64 {
+                self.rem_small(other)
+            }
+        }
+    };
+}
+
+impl_div_rem_small!(Grou);
+impl_div_rem_small!(&Grou);
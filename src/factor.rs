@@ -0,0 +1,168 @@
+use super::grou::Grou;
+use super::modular::Montgomery;
+use super::sieve::{sieve_primes, SMALL_PRIMES};
+
+/// Smoothness bound used by Grou::factor for Pollard's p - 1 method.
+pub const DEFAULT_P_MINUS_1_BOUND: u64 = 20_000;
+
+impl Grou {
+    /// Returns the prime factorization of self, as (prime, exponent) pairs
+    /// sorted by prime. 1 has an empty factorization. Panics on 0.
+    pub fn factor(&self) -> Vec<(Grou, u32)> {
+        self.factor_with_bound(DEFAULT_P_MINUS_1_BOUND)
+    }
+
+    /// Same as factor, with a custom smoothness bound for the p - 1 method.
+    ///
+    /// Small factors are removed by trial division. What remains is split
+    /// with Pollard's p - 1 method, then Brent's variant of Pollard's rho,
+    /// until every part passes the BPSW primality test.
+    pub fn factor_with_bound(&self, p_minus_1_bound: u64) -> Vec<(Grou, u32)> {
+        if self.is_zero() {
+            panic!("Cannot factor 0");
+        }
+
+        let mut primes: Vec<Grou> = Vec::new();
+        let mut remaining = self.clone();
+        remaining.trim();
+        for &p in SMALL_PRIMES.iter() {
+            loop {
+                let (quotient, remainder) = remaining.div_rem_small(p);
+                if remainder != 0 {
+                    break;
+                }
+                primes.push(Grou::from(p));
+                remaining = quotient;
+            }
+        }
+
+        let mut composites = vec![remaining];
+        while let Some(n) = composites.pop() {
+            if n.is_one() {
+                continue;
+            }
+            if n.is_prime_bpsw() {
+                primes.push(n);
+                continue;
+            }
+            if let Some((base, exponent)) = n.perfect_power() {
+                for _ in 0..exponent {
+                    composites.push(base.clone());
+                }
+                continue;
+            }
+
+            let divisor = match pollard_p_minus_1(&n, p_minus_1_bound) {
+                Some(divisor) => divisor,
+                None => find_divisor_rho(&n),
+            };
+            composites.push(&n / &divisor);
+            composites.push(divisor);
+        }
+
+        primes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut factors: Vec<(Grou, u32)> = Vec::new();
+        for p in primes {
+            match factors.last_mut() {
+                Some((last, count)) if *last == p => *count += 1,
+                _ => factors.push((p, 1)),
+            }
+        }
+        factors
+    }
+}
+
+/// Pollard's p - 1 method. Finds a prime factor p of n when p - 1 is a
+/// product of prime powers no larger than the bound.
+///
+/// Returns a nontrivial divisor of n, or None if none was found. n must be
+/// odd and composite.
+pub fn pollard_p_minus_1(n: &Grou, bound: u64) -> Option<Grou> {
+    let context = Montgomery::new(n)?;
+
+    // a = 2^E, where E is the product of the largest power of each prime
+    // that fits under the bound.
+    let mut a = context.to_montgomery(&Grou::from(2));
+    for p in sieve_primes(bound as usize) {
+        let mut prime_power = p;
+        while let Some(next) = prime_power.checked_mul(p).filter(|next| *next <= bound) {
+            prime_power = next;
+        }
+        a = context.pow_montgomery(&a, &Grou::from(prime_power));
+    }
+
+    let a = context.from_montgomery(&a);
+    let divisor = a.sub_mod(&Grou::from(1), n).gcd(n);
+    if divisor.is_one() || &divisor == n {
+        None
+    } else {
+        Some(divisor)
+    }
+}
+
+/// Brent's variant of Pollard's rho method, iterating x -> x^2 + c mod n
+/// from x = 2.
+///
+/// Returns a nontrivial divisor of n, or None if the cycle closed without
+/// separating the factors, in which case another c should be tried. n must
+/// be odd and composite.
+pub fn pollard_rho_brent(n: &Grou, c: u64) -> Option<Grou> {
+    // Number of steps whose differences are multiplied together before
+    // taking a gcd.
+    const BATCH: usize = 128;
+
+    let context = Montgomery::new(n)?;
+    let c = context.to_montgomery(&Grou::from(c));
+    let step = |x: &Grou| context.mul(x, x).add_mod(&c, n);
+
+    let mut y = context.to_montgomery(&Grou::from(2));
+    let mut x = y.clone();
+    let mut saved_y = y.clone();
+    let mut product = context.one();
+    let mut divisor = Grou::from(1);
+    let mut cycle_length = 1usize;
+
+    while divisor.is_one() {
+        x = y.clone();
+        for _ in 0..cycle_length {
+            y = step(&y);
+        }
+
+        let mut done = 0;
+        while done < cycle_length && divisor.is_one() {
+            saved_y = y.clone();
+            for _ in 0..std::cmp::min(BATCH, cycle_length - done) {
+                y = step(&y);
+                product = context.mul(&product, &x.sub_mod(&y, n));
+            }
+            // Montgomery form only adds a factor of R, which is coprime to n.
+            divisor = product.gcd(n);
+            done += BATCH;
+        }
+        cycle_length *= 2;
+    }
+
+    // The batch overshot and collected every factor: redo it one step at
+    // a time.
+    if &divisor == n {
+        loop {
+            saved_y = step(&saved_y);
+            divisor = x.sub_mod(&saved_y, n).gcd(n);
+            if !divisor.is_one() {
+                break;
+            }
+        }
+    }
+
+    if &divisor == n {
+        None
+    } else {
+        Some(divisor)
+    }
+}
+
+fn find_divisor_rho(n: &Grou) -> Grou {
+    (1u64..)
+        .find_map(|c| pollard_rho_brent(n, c))
+        .unwrap()
+}
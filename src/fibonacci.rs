@@ -0,0 +1,39 @@
+use super::grou::Grou;
+
+impl Grou {
+    /// Returns the n-th Fibonacci number, with F(0) = 0 and F(1) = 1.
+    pub fn fibonacci(n: u64) -> Grou {
+        Grou::fib_pair(n).0
+    }
+
+    /// Returns the n-th Lucas number, with L(0) = 2 and L(1) = 1.
+    pub fn lucas(n: u64) -> Grou {
+        // L(n) = 2 F(n + 1) - F(n)
+        let (f_n, f_next) = Grou::fib_pair(n);
+        f_next.shift_left(1) - f_n
+    }
+
+    /// Returns (F(n), F(n + 1)).
+    ///
+    /// Uses the fast doubling identities
+    ///   F(2k) = F(k) (2 F(k + 1) - F(k))
+    ///   F(2k + 1) = F(k)^2 + F(k + 1)^2
+    /// going through the bits of n from the top, which takes O(log n)
+    /// multiplications.
+    pub fn fib_pair(n: u64) -> (Grou, Grou) {
+        let mut f_k = Grou::from(0);
+        let mut f_next = Grou::from(1);
+        for position in (0..(64 - n.leading_zeros())).rev() {
+            let f_2k = &f_k * (f_next.shift_left(1) - &f_k);
+            let f_2k_next = &f_k * &f_k + &f_next * &f_next;
+            if (n >> position) & 1 == 1 {
+                f_next = &f_2k + &f_2k_next;
+                f_k = f_2k_next;
+            } else {
+                f_k = f_2k;
+                f_next = f_2k_next;
+            }
+        }
+        (f_k, f_next)
+    }
+}
@@ -0,0 +1,439 @@
+use super::decimal::GrouDecimal;
+use super::grou::Grou;
+use super::rounding::RoundingMode;
+use super::signed::GrouCoeff;
+
+/// A binary floating point number, (-1)^negative * mantissa * 2^exponent,
+/// with a precision of at most `precision` significant bits.
+///
+/// Every operation computes the exact result, or enough of it to know
+/// which way to round, and then rounds once. The operators round to the
+/// larger precision of the two operands with RoundingMode::HalfEven; the
+/// `_with` methods take the precision and rounding mode explicitly.
+///
+/// The mantissa is kept odd (or 0 for zero), so each value has a single
+/// representation. Comparisons only look at the value, not the precision.
+#[derive(Clone, Debug)]
+pub struct GrouFloat {
+    negative: bool,
+    mantissa: Grou,
+    exponent: i64,
+    precision: u32,
+}
+
+impl GrouFloat {
+    /// Returns (-1)^negative * mantissa * 2^exponent, rounded to the
+    /// precision. Panics if the precision is 0.
+    pub fn new(
+        negative: bool,
+        mantissa: Grou,
+        exponent: i64,
+        precision: u32,
+        mode: RoundingMode,
+    ) -> GrouFloat {
+        if precision == 0 {
+            panic!("Precision must be at least 1");
+        }
+        let mut mantissa = mantissa;
+        let mut exponent = exponent;
+        let bits = mantissa.bit_length();
+        if bits > precision as usize {
+            let dropped = bits - precision as usize;
+            let quotient = mantissa.shift_right(dropped);
+            let remainder = &mantissa - quotient.shift_left(dropped);
+            let divisor = Grou::from(1).shift_left(dropped);
+            mantissa = mode.round_quotient(quotient, &remainder, &divisor, negative);
+            exponent += dropped as i64;
+        }
+
+        if mantissa.is_zero() {
+            return GrouFloat::zero(precision);
+        }
+        let zeros = mantissa.trailing_zeros();
+        GrouFloat {
+            negative,
+            mantissa: mantissa.shift_right(zeros),
+            exponent: exponent + zeros as i64,
+            precision,
+        }
+    }
+
+    pub fn zero(precision: u32) -> GrouFloat {
+        GrouFloat {
+            negative: false,
+            mantissa: Grou::from(0),
+            exponent: 0,
+            precision,
+        }
+    }
+
+    #[inline]
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Returns the odd mantissa, or 0.
+    #[inline]
+    pub fn mantissa(&self) -> &Grou {
+        &self.mantissa
+    }
+
+    #[inline]
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns self rounded to another precision.
+    pub fn with_precision(&self, precision: u32, mode: RoundingMode) -> GrouFloat {
+        GrouFloat::new(
+            self.negative,
+            self.mantissa.clone(),
+            self.exponent,
+            precision,
+            mode,
+        )
+    }
+
+    /// Converts a finite f64, rounding if the precision is below 53 bits.
+    /// Panics on NaN and infinities.
+    pub fn from_f64(x: f64, precision: u32, mode: RoundingMode) -> GrouFloat {
+        if !x.is_finite() {
+            panic!("Cannot convert a non-finite f64");
+        }
+        let bits = x.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        GrouFloat::new(
+            x.is_sign_negative(),
+            Grou::from(mantissa),
+            exponent,
+            precision,
+            mode,
+        )
+    }
+
+    /// Returns the nearest f64, with ties to even. Values beyond the range
+    /// of f64 become infinities, and tiny ones become subnormals or 0.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        // The last kept bit is 2^-1074 for subnormals, and 52 bits below
+        // the leading one otherwise.
+        let top = self.exponent + self.mantissa.bit_length() as i64;
+        if top > 1025 {
+            return if self.negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+        }
+        let last_bit = std::cmp::max(top - 53, -1074);
+        let (mantissa, exponent) = if last_bit > self.exponent {
+            let dropped = (last_bit - self.exponent) as usize;
+            let quotient = self.mantissa.shift_right(dropped);
+            let remainder = &self.mantissa - quotient.shift_left(dropped);
+            let divisor = Grou::from(1).shift_left(dropped);
+            let rounded = RoundingMode::HalfEven.round_quotient(
+                quotient,
+                &remainder,
+                &divisor,
+                self.negative,
+            );
+            (rounded, last_bit)
+        } else {
+            (self.mantissa.clone(), self.exponent)
+        };
+
+        // The mantissa has at most 54 bits (53 and a rounding carry, which
+        // leaves a power of 2), so it converts exactly. The power of 2 is
+        // applied in two steps near the ends of the range, so that no step
+        // leaves the normal range before the last one.
+        let mantissa = mantissa.data.first().copied().unwrap_or(0) as f64;
+        let magnitude = if exponent < -1022 {
+            mantissa * power_of_two(exponent + 1022) * power_of_two(-1022)
+        } else if exponent > 971 {
+            mantissa * power_of_two(971) * power_of_two(exponent - 971)
+        } else {
+            mantissa * power_of_two(exponent)
+        };
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns self rounded to the given number of decimal places.
+    pub fn to_decimal(&self, scale: u32, mode: RoundingMode) -> GrouDecimal {
+        let scaled = &self.mantissa * Grou::from(10).pow(scale);
+        let magnitude = if self.exponent >= 0 {
+            scaled.shift_left(self.exponent as usize)
+        } else {
+            let shift = (-self.exponent) as usize;
+            let quotient = scaled.shift_right(shift);
+            let remainder = &scaled - quotient.shift_left(shift);
+            let divisor = Grou::from(1).shift_left(shift);
+            mode.round_quotient(quotient, &remainder, &divisor, self.negative)
+        };
+        GrouDecimal::new(GrouCoeff::new(self.negative, magnitude), scale)
+    }
+
+    // The signed mantissa, shifted left so that its exponent becomes the
+    // given one, which must not be above self.exponent.
+    fn coeff_at(&self, exponent: i64) -> GrouCoeff {
+        let shifted = self
+            .mantissa
+            .shift_left((self.exponent - exponent) as usize);
+        GrouCoeff::new(self.negative, shifted)
+    }
+
+    // One past the position of the leading bit.
+    fn top(&self) -> i64 {
+        self.exponent + self.mantissa.bit_length() as i64
+    }
+
+    /// Returns self + other, rounded to the precision with the mode.
+    pub fn add_with(&self, other: &GrouFloat, precision: u32, mode: RoundingMode) -> GrouFloat {
+        if self.is_zero() {
+            return other.with_precision(precision, mode);
+        }
+        if other.is_zero() {
+            return self.with_precision(precision, mode);
+        }
+        let (big, small) = if self.top() >= other.top() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        // When the smaller operand lies entirely below both the larger one's
+        // mantissa and the rounding bit, only its sign and the fact that it
+        // is nonzero matter. Replacing it with a single low bit keeps the
+        // alignment shift small when the exponents are far apart.
+        let floor = std::cmp::min(big.exponent, big.top() - precision as i64 - 2);
+        let sticky;
+        let small = if small.top() <= floor {
+            sticky = GrouFloat {
+                negative: small.negative,
+                mantissa: Grou::from(1),
+                exponent: floor - 1,
+                precision: 1,
+            };
+            &sticky
+        } else {
+            small
+        };
+
+        let exponent = std::cmp::min(big.exponent, small.exponent);
+        let sum = big.coeff_at(exponent) + small.coeff_at(exponent);
+        let (negative, mantissa) = sum.into_parts();
+        GrouFloat::new(negative, mantissa, exponent, precision, mode)
+    }
+
+    /// Returns self - other, rounded to the precision with the mode.
+    pub fn sub_with(&self, other: &GrouFloat, precision: u32, mode: RoundingMode) -> GrouFloat {
+        self.add_with(&-other, precision, mode)
+    }
+
+    /// Returns self * other, rounded to the precision with the mode.
+    pub fn mul_with(&self, other: &GrouFloat, precision: u32, mode: RoundingMode) -> GrouFloat {
+        GrouFloat::new(
+            self.negative ^ other.negative,
+            &self.mantissa * &other.mantissa,
+            self.exponent + other.exponent,
+            precision,
+            mode,
+        )
+    }
+
+    /// Returns self / other, rounded to the precision with the mode. Panics
+    /// if other is 0.
+    pub fn div_with(&self, other: &GrouFloat, precision: u32, mode: RoundingMode) -> GrouFloat {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        if self.is_zero() {
+            return GrouFloat::zero(precision);
+        }
+        // Shift the dividend so the quotient has two bits beyond the
+        // precision. A nonzero remainder is kept as one more low bit.
+        let shift = (precision as i64 + 2 + other.mantissa.bit_length() as i64
+            - self.mantissa.bit_length() as i64)
+            .max(0) as usize;
+        let (quotient, remainder) = self.mantissa.shift_left(shift).div_rem(&other.mantissa);
+        let (mantissa, exponent) = sticky_bit(
+            quotient,
+            &remainder,
+            self.exponent - other.exponent - shift as i64,
+        );
+        GrouFloat::new(
+            self.negative ^ other.negative,
+            mantissa,
+            exponent,
+            precision,
+            mode,
+        )
+    }
+
+    /// Returns the square root of self, rounded to the precision with the
+    /// mode. Panics if self is negative.
+    pub fn sqrt_with(&self, precision: u32, mode: RoundingMode) -> GrouFloat {
+        if self.negative {
+            panic!("Square root of a negative number");
+        }
+        if self.is_zero() {
+            return GrouFloat::zero(precision);
+        }
+        // Shift so the exponent left over is even and the integer root has
+        // two bits beyond the precision.
+        let wanted = 2 * (precision as i64 + 2) - self.mantissa.bit_length() as i64;
+        let mut shift = wanted.max(0);
+        if (self.exponent - shift) % 2 != 0 {
+            shift += 1;
+        }
+        let (root, remainder) = self.mantissa.shift_left(shift as usize).sqrt_rem();
+        let (mantissa, exponent) = sticky_bit(root, &remainder, (self.exponent - shift) / 2);
+        GrouFloat::new(false, mantissa, exponent, precision, mode)
+    }
+
+    /// Returns the square root of self, at the precision of self with
+    /// ties to even.
+    pub fn sqrt(&self) -> GrouFloat {
+        self.sqrt_with(self.precision, RoundingMode::HalfEven)
+    }
+}
+
+// 2^exponent, for an exponent in the normal range of f64.
+fn power_of_two(exponent: i64) -> f64 {
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+// The exact result lies strictly between value and value + 1 (at the given
+// exponent) when the remainder is nonzero. Appending a 1 bit records that
+// without changing how it rounds.
+fn sticky_bit(value: Grou, remainder: &Grou, exponent: i64) -> (Grou, i64) {
+    if remainder.is_zero() {
+        (value, exponent)
+    } else {
+        (value.shift_left(1) + Grou::from(1), exponent - 1)
+    }
+}
+
+impl PartialEq for GrouFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.negative == other.negative
+            && self.mantissa == other.mantissa
+            && self.exponent == other.exponent
+    }
+}
+
+impl std::cmp::PartialOrd for GrouFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+        let magnitude_order = if self.is_zero() || other.is_zero() {
+            other.is_zero().cmp(&self.is_zero())
+        } else if self.top() != other.top() {
+            self.top().cmp(&other.top())
+        } else {
+            let exponent = std::cmp::min(self.exponent, other.exponent);
+            let lhs = self
+                .mantissa
+                .shift_left((self.exponent - exponent) as usize);
+            let rhs = other
+                .mantissa
+                .shift_left((other.exponent - exponent) as usize);
+            lhs.partial_cmp(&rhs)?
+        };
+        Some(match (self.negative, other.negative) {
+            (false, false) => magnitude_order,
+            (true, true) => magnitude_order.reverse(),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        })
+    }
+}
+
+/// Prints the exact value in decimal, or the value rounded to the given
+/// number of decimal places with ties to even, as in `{:.10}`.
+impl std::fmt::Display for GrouFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let scale = match f.precision() {
+            Some(places) => places as u32,
+            None => std::cmp::max(-self.exponent, 0) as u32,
+        };
+        let decimal = self.to_decimal(scale, RoundingMode::HalfEven);
+        std::fmt::Display::fmt(&decimal, f)
+    }
+}
+
+impl std::ops::Neg for GrouFloat {
+    type Output = GrouFloat;
+    fn neg(mut self) -> GrouFloat {
+        self.negative = !self.negative && !self.is_zero();
+        self
+    }
+}
+
+impl std::ops::Neg for &GrouFloat {
+    type Output = GrouFloat;
+    fn neg(self) -> GrouFloat {
+        -self.clone()
+    }
+}
+
+macro_rules! impl_ops_float {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::ops::Add<$rhs> for $lhs {
+            type Output = GrouFloat;
+            fn add(self, other: $rhs) -> GrouFloat {
+                let precision = std::cmp::max(self.precision, other.precision);
+                self.add_with(&other, precision, RoundingMode::HalfEven)
+            }
+        }
+
+        impl std::ops::Sub<$rhs> for $lhs {
+            type Output = GrouFloat;
+            fn sub(self, other: $rhs) -> GrouFloat {
+                let precision = std::cmp::max(self.precision, other.precision);
+                self.sub_with(&other, precision, RoundingMode::HalfEven)
+            }
+        }
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = GrouFloat;
+            fn mul(self, other: $rhs) -> GrouFloat {
+                let precision = std::cmp::max(self.precision, other.precision);
+                self.mul_with(&other, precision, RoundingMode::HalfEven)
+            }
+        }
+
+        impl std::ops::Div<$rhs> for $lhs {
+            type Output = GrouFloat;
+            fn div(self, other: $rhs) -> GrouFloat {
+                let precision = std::cmp::max(self.precision, other.precision);
+                self.div_with(&other, precision, RoundingMode::HalfEven)
+            }
+        }
+    };
+}
+
+impl_ops_float!(GrouFloat, GrouFloat);
+impl_ops_float!(GrouFloat, &GrouFloat);
+impl_ops_float!(&GrouFloat, GrouFloat);
+impl_ops_float!(&GrouFloat, &GrouFloat);
@@ -0,0 +1,69 @@
+use super::grou::Grou;
+use super::signed::GrouCoeff;
+
+impl Grou {
+    /// Greatest common divisor, using Euclid's algorithm.
+    /// gcd(0, 0) is 0.
+    pub fn gcd(&self, other: &Grou) -> Grou {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a.trim();
+        if a.is_empty() {
+            return Grou::from(0);
+        }
+        a
+    }
+
+    /// Returns (g, x, y) such that self * x + other * y = g, where g is
+    /// gcd(self, other). The coefficients are the ones produced by the
+    /// extended Euclidean algorithm, so |x| <= other / g and |y| <= self / g.
+    pub fn extended_gcd(&self, other: &Grou) -> (Grou, GrouCoeff, GrouCoeff) {
+        let mut old_r = self.clone();
+        let mut r = other.clone();
+        let mut old_s = GrouCoeff::from(1);
+        let mut s = GrouCoeff::from(0);
+        let mut old_t = GrouCoeff::from(0);
+        let mut t = GrouCoeff::from(1);
+
+        while !r.is_zero() {
+            let (q, new_r) = old_r.div_rem(&r);
+            let q = GrouCoeff::from(q);
+            (old_r, r) = (r, new_r);
+
+            let new_s = &old_s - &q * &s;
+            (old_s, s) = (s, new_s);
+
+            let new_t = &old_t - &q * &t;
+            (old_t, t) = (t, new_t);
+        }
+
+        old_r.trim();
+        if old_r.is_empty() {
+            old_r = Grou::from(0);
+        }
+        (old_r, old_s, old_t)
+    }
+
+    /// Returns x in [0, modulus) such that self * x = 1 (mod modulus), or
+    /// None if self and modulus are not coprime, or if modulus is 0.
+    pub fn mod_inverse(&self, modulus: &Grou) -> Option<Grou> {
+        if modulus.is_zero() {
+            return None;
+        }
+        if modulus.is_one() {
+            return Some(Grou::from(0));
+        }
+
+        let reduced = self % modulus;
+        let (g, x, _) = reduced.extended_gcd(modulus);
+        if !g.is_one() {
+            return None;
+        }
+        Some(x.rem_euclid(modulus))
+    }
+}
@@ -0,0 +1,68 @@
+use super::grou::Grou;
+
+impl Grou {
+    /// Jacobi symbol (self / n), for odd n. Panics if n is even.
+    ///
+    /// Uses the binary algorithm: factors of 2 are shifted out of self, the
+    /// operands are swapped with quadratic reciprocity to keep self >= n,
+    /// and n is subtracted. The sign changes only depend on the residues
+    /// modulo 8, read off the lowest block, so no division is needed.
+    pub fn jacobi(&self, n: &Grou) -> i8 {
+        if n.is_even() {
+            panic!("The Jacobi symbol is only defined for odd n");
+        }
+
+        let mut a = self.clone();
+        let mut n = n.clone();
+        let mut result = 1i8;
+        loop {
+            if a.is_zero() {
+                return if n.is_one() { result } else { 0 };
+            }
+
+            // (2 / n) = -1 iff n = 3, 5 mod 8.
+            let twos = a.trailing_zeros();
+            a = a.shift_right(twos);
+            let n_mod_8 = n.data[0] & 7;
+            if twos & 1 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+                result = -result;
+            }
+
+            // Reciprocity: (a / n) = (n / a), unless both are 3 mod 4.
+            if a < n {
+                (a, n) = (n, a);
+                if a.data[0] & 3 == 3 && n.data[0] & 3 == 3 {
+                    result = -result;
+                }
+            }
+            a = &a - &n;
+        }
+    }
+
+    /// Legendre symbol (self / p), for an odd prime p. This is 0 if p
+    /// divides self, 1 if self is a nonzero square modulo p and -1
+    /// otherwise.
+    pub fn legendre(&self, p: &Grou) -> i8 {
+        self.jacobi(p)
+    }
+
+    /// Kronecker symbol (self / n), which extends the Jacobi symbol to every
+    /// n, with (a / 2) = 0 for even a, 1 for a = 1, 7 mod 8 and -1 for
+    /// a = 3, 5 mod 8, and (a / 0) = 1 only for a = 1.
+    pub fn kronecker(&self, n: &Grou) -> i8 {
+        if n.is_zero() {
+            return if self.is_one() { 1 } else { 0 };
+        }
+        if n.is_even() && self.is_even() {
+            return 0;
+        }
+
+        let twos = n.trailing_zeros();
+        let mut result = 1i8;
+        let a_mod_8 = self.data.first().copied().unwrap_or(0) & 7;
+        if twos & 1 == 1 && (a_mod_8 == 3 || a_mod_8 == 5) {
+            result = -result;
+        }
+        result * self.jacobi(&n.shift_right(twos))
+    }
+}
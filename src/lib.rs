@@ -1,22 +1,52 @@
 #![feature(bigint_helper_methods)]
 #![feature(destructuring_assignment)]
 
+pub mod block_iterator;
+pub mod constants;
+pub mod crt;
+pub mod decimal;
+pub mod factor;
+pub mod float;
+pub mod limbs;
+pub mod modular;
+pub mod mul;
 pub mod radix_convert;
-mod block_iterator;
+pub mod random;
+pub mod ratio;
+pub mod rounding;
+pub mod signed;
+pub mod toom;
+mod combinatorics;
+mod division;
+mod fibonacci;
+mod gcd;
+mod jacobi;
+mod limb_vec;
+mod ops;
+mod parallel;
+mod primality;
+mod roots;
+mod shift;
+mod sieve;
+mod subset_mut;
 
 pub mod grou {
-    use crate::block_iterator::*;
+    use crate::block_iterator::{calculate_block_length, BlockIterator};
+    use crate::limb_vec::LimbVec;
+
+    pub use crate::ops::AsLimbs;
+    pub use crate::subset_mut::GrouSubsetMut;
 
     #[derive(Clone, PartialEq, Eq, Debug)]
     pub struct Grou {
-        data: Vec<u64>,
+        pub(crate) data: LimbVec,
     }
 
     impl Grou {
         // Empty array. Preallocated to size.
         pub fn empty(size: usize) -> Grou {
             Grou {
-                data: Vec::with_capacity(size),
+                data: LimbVec::with_capacity(size),
             }
         }
 
@@ -33,9 +63,31 @@ pub mod grou {
             self.data.is_empty()
         }
 
+        ///Returns whether the number is equal to 0.
+        ///Unlike is_empty, this ignores any high 0 blocks.
+        #[inline]
+        pub fn is_zero(&self) -> bool {
+            self.data.iter().all(|val| *val == 0)
+        }
+
+        ///Returns whether the number is equal to 1.
+        #[inline]
+        pub fn is_one(&self) -> bool {
+            match self.data.split_first() {
+                Some((first, rest)) => *first == 1 && rest.iter().all(|val| *val == 0),
+                None => false,
+            }
+        }
+
+        ///Returns whether the lowest bit is unset. 0 is even.
+        #[inline]
+        pub fn is_even(&self) -> bool {
+            self.data.first().is_none_or(|val| val & 1 == 0)
+        }
+
         /// Removes trailing 0s.
         #[inline]
-        fn trim(self: &mut Self) {
+        pub(crate) fn trim(self: &mut Self) {
             let mut final_length = self.len();
             for val in self.data.iter().rev() {
                 if *val == 0u64 {
@@ -55,54 +107,17 @@ pub mod grou {
     impl From<u64> for Grou {
         fn from(small_num: u64) -> Grou {
             Grou {
-                data: vec![small_num],
+                data: LimbVec::from_slice(&[small_num]),
             }
         }
     }
 
     impl From<Vec<u64>> for Grou {
         fn from(num: Vec<u64>) -> Grou {
-            Grou { data: num }
+            Grou { data: num.into() }
         }
     }
 
-    macro_rules! impl_partial_cmp {
-        ($type1: ty) => {
-            impl std::cmp::PartialOrd for $type1 {
-                fn partial_cmp(self: &Self, other: &Self) -> Option<std::cmp::Ordering> {
-                    let mut self_len = self.len();
-                    let mut iter_self = self.data.iter().rev().peekable();
-
-                    let mut other_len = other.len();
-                    let mut iter_other = other.data.iter().rev().peekable();
-
-                    while iter_self.next_if_eq(&&0u64).is_some() {
-                        self_len -= 1;
-                    }
-
-                    while iter_other.next_if_eq(&&0u64).is_some() {
-                        other_len -= 1;
-                    }
-
-                    if self_len != other_len {
-                        return self_len.partial_cmp(&other_len);
-                    } else {
-                        for (self_val, other_val) in iter_self.zip(iter_other) {
-                            if self_val != other_val {
-                                return self_val.partial_cmp(&other_val);
-                            }
-                        }
-                        // At this point all values are identical.
-                        return Some(std::cmp::Ordering::Equal);
-                    }
-                }
-            }
-        };
-    }
-
-    impl_partial_cmp!(Grou);
-    impl_partial_cmp!(GrouSubset<'_>);
-
     impl Grou {
         pub fn addition_small(&mut self, rhs:u64) {
             if self.len() == 0 {
@@ -143,170 +158,6 @@ pub mod grou {
         }
     }
 
-    // Shoutout for a tip from Globi on Discord that opened the way for this.
-    macro_rules! iter_addition {
-        ($lhs:expr, $rhs:expr, $result:expr ) => {
-            let mut carry = false;
-
-            // Create iterators
-            let mut largest;
-            let smallest;
-
-            if ($lhs.len() > $rhs.len()) {
-                largest = $lhs.data.iter();
-                smallest = $rhs.data.iter();
-            } else {
-                smallest = $lhs.data.iter();
-                largest = $rhs.data.iter();
-            }
-
-            // Do until smallest iterator is exhausted.
-            for (small, large) in smallest.zip(largest.by_ref()) {
-                let (value, tmp_carry) = small.carrying_add(*large, carry);
-                carry = tmp_carry;
-                $result.push(value);
-            }
-
-            // Do the rest.
-            for large in largest {
-                if (carry) {
-                    let (value, tmp_carry) = large.carrying_add(0u64, carry);
-                    carry = tmp_carry;
-                    $result.push(value);
-                } else {
-                    // if no carry bit, then u32 + 0 can't overflow.
-                    $result.push(*large);
-                }
-            }
-
-            // Final carry.
-            if carry {
-                $result.push(1);
-            }
-        };
-    }
-
-    macro_rules! addition_impl_grou {
-        ($type1:ty, $type2:ty) => {
-            impl std::ops::Add<$type2> for $type1 {
-                type Output = Grou;
-
-                fn add(self: Self, other: $type2) -> Grou {
-                    let preallocation_size = std::cmp::max(self.len(), other.len()) + 1;
-                    let mut result = Grou::empty(preallocation_size);
-                    iter_addition!(self, other, result.data);
-                    return result;
-                }
-            }
-        };
-    }
-
-    addition_impl_grou!(Grou, Grou);
-    addition_impl_grou!(&Grou, Grou);
-    addition_impl_grou!(Grou, &Grou);
-    addition_impl_grou!(&Grou, &Grou);
-
-    macro_rules! add_assign_impl_grou {
-        ($type2:ty) => {
-            impl std::ops::AddAssign<$type2> for Grou {
-                fn add_assign(self: &mut Grou, other: $type2) {
-                    let preallocation_size = std::cmp::max(self.len(), other.len()) + 1;
-                    let mut final_vec: Vec<u64> = Vec::with_capacity(preallocation_size);
-                    iter_addition!(self, other, final_vec);
-                    self.data = final_vec;
-                }
-            }
-        };
-    }
-
-    add_assign_impl_grou!(Grou);
-    add_assign_impl_grou!(&Grou);
-
-    macro_rules! impl_sub_unchecked {
-        ($lhs: ty, $rhs: ty) => {
-            impl $lhs {
-                fn sub_unchecked(self: &Self, other: &$rhs) -> Grou {
-                    let mut borrow = false;
-                    let mut result = Vec::<u64>::new();
-
-                    // Assume that self > other.
-                    let mut lhs = self.data.iter();
-                    let rhs = other.data.iter();
-                    for (j, i) in rhs.zip(lhs.by_ref()) {
-                        let (value, tmp_borrow) = i.borrowing_sub(*j, borrow);
-                        result.push(value);
-                        borrow = tmp_borrow;
-                    }
-
-                    // Finish using lhs.
-                    for i in lhs {
-                        if borrow {
-                            let (value, tmp_borrow) = i.borrowing_sub(0u64, borrow);
-                            result.push(value);
-                            borrow = tmp_borrow;
-                        } else {
-                            result.push(*i);
-                        }
-                    }
-
-                    let mut g = Grou::from(result);
-                    g.trim();
-                    return g;
-                }
-            }
-        };
-    }
-
-    impl_sub_unchecked!(Grou, Grou);
-    impl_sub_unchecked!(GrouSubset<'_>, GrouSubset<'_>);
-
-    //impl_sub_unchecked!(Grou, GrouSubset<'_>);
-
-    macro_rules! impl_sub {
-        ($lhs: ty, $rhs: ty) => {
-            impl std::ops::Sub<$rhs> for $lhs {
-                type Output = Grou;
-                fn sub(self, other: $rhs) -> Grou {
-                    match self.partial_cmp(&other).unwrap() {
-                        std::cmp::Ordering::Less => {
-                            panic!("Subtraction leads to underflow");
-                        }
-                        std::cmp::Ordering::Equal => {
-                            return Grou::from(0);
-                        }
-                        std::cmp::Ordering::Greater => {
-                            return self.sub_unchecked(&other);
-                        }
-                    }
-                }
-            }
-        };
-    }
-
-    impl_sub!(Grou, Grou);
-    impl_sub!(Grou, &Grou);
-    impl_sub!(&Grou, Grou);
-    impl_sub!(&Grou, &Grou);
-
-    impl_sub!(GrouSubset<'_>, GrouSubset<'_>);
-
-    macro_rules! impl_sub_with_sign {
-        ($lhs:ty, $rhs:ty) => {
-            impl $lhs {
-                pub fn sub_with_sign(&self, other: $rhs) -> (bool, Grou) {
-                    match self.partial_cmp(other).unwrap() {
-                        std::cmp::Ordering::Less => return (true, other.sub_unchecked(self)),
-                        std::cmp::Ordering::Equal => return (false, Grou::from(0)),
-                        std::cmp::Ordering::Greater => return (true, self.sub_unchecked(other)),
-                    }
-                }
-            }
-        };
-    }
-
-    impl_sub_with_sign!(Grou, &Grou);
-    impl_sub_with_sign!(GrouSubset<'_>, &GrouSubset<'_>);
-
     impl Grou {
         pub fn subset<'a>(self: &'a Self, start: usize, end: usize) -> GrouSubset<'a> {
             return GrouSubset {
@@ -336,35 +187,29 @@ pub mod grou {
             }
         }
 
-        // TODO: Refactor this mess.
         pub fn split_2<'a>(self: &'a Self) -> (GrouSubset<'a>, GrouSubset<'a>) {
-            let mut x = self.make_chunks(2).map(|x| GrouSubset { data: x });
-
-            let i = x.next().unwrap_or(GrouSubset { data: &[] });
-            let j = x.next().unwrap_or(GrouSubset { data: &[] });
-
+            let [i, j] = self.split_n::<2>();
             (i, j)
         }
 
         pub fn split_3<'a>(self: &'a Self) -> (GrouSubset<'a>, GrouSubset<'a>, GrouSubset<'a>) {
-            let mut x = self.make_chunks(3).map(|x| GrouSubset { data: x });
-
-            let i = x.next().unwrap_or(GrouSubset { data: &[] });
-            let j = x.next().unwrap_or(GrouSubset { data: &[] });
-            let k = x.next().unwrap_or(GrouSubset { data: &[] });
-
+            let [i, j, k] = self.split_n::<3>();
             (i, j, k)
         }
 
-        // Splits the number into N chunks. Thanks to "The Lua Moon" on Discord
-        // for the suggestion.
-        fn make_chunks<'a>(self: &'a Self, n: usize) -> std::slice::Chunks<'a, u64> {
-            let offset = match self.len() % n {
-                0 => 0,
-                _ => 1,
-            };
-            let chunk_length = self.len() / n + offset;
-            return self.data[..].chunks(chunk_length);
+        /// Splits the number into N parts of equal length, from the least
+        /// significant one up. The last nonempty part may be shorter, and
+        /// any parts past the end of the number are empty. N = 0 gives no
+        /// parts.
+        pub fn split_n<'a, const N: usize>(&'a self) -> [GrouSubset<'a>; N] {
+            let mut chunks = self.make_chunks(N);
+            std::array::from_fn(|_| chunks.next().unwrap_or(GrouSubset { data: &[] }))
+        }
+
+        /// Splits the number into at most n chunks, and none for n = 0.
+        /// Thanks to "The Lua Moon" on Discord for the suggestion.
+        pub fn make_chunks<'a>(self: &'a Self, n: usize) -> BlockIterator<'a> {
+            BlockIterator::new(self, calculate_block_length(n, self, self))
         }
     }
 
@@ -379,34 +224,6 @@ pub mod grou {
         }
     }
 
-    macro_rules! impl_addition_grousubset {
-        ($type1: ty, $type2: ty) => {
-            impl std::ops::Add<$type2> for $type1 {
-                type Output = Grou;
-                fn add(self, other: $type2) -> Grou {
-                    let mut results = Vec::new();
-                    iter_addition!(self, other, results);
-                    return Grou { data: results };
-                }
-            }
-        };
-    }
-
-    impl_addition_grousubset!(GrouSubset<'_>, GrouSubset<'_>);
-    impl_addition_grousubset!(GrouSubset<'_>, &GrouSubset<'_>);
-    impl_addition_grousubset!(&GrouSubset<'_>, GrouSubset<'_>);
-    impl_addition_grousubset!(&GrouSubset<'_>, &GrouSubset<'_>);
-
-    //Implement GrouSubset/Grou addition
-    impl_addition_grousubset!(GrouSubset<'_>, Grou);
-    impl_addition_grousubset!(GrouSubset<'_>, &Grou);
-    impl_addition_grousubset!(&GrouSubset<'_>, Grou);
-    impl_addition_grousubset!(&GrouSubset<'_>, &Grou);
-    impl_addition_grousubset!(Grou, GrouSubset<'_>);
-    impl_addition_grousubset!(Grou, &GrouSubset<'_>);
-    impl_addition_grousubset!(&Grou, GrouSubset<'_>);
-    impl_addition_grousubset!(&Grou, &GrouSubset<'_>);
-
 
     impl Grou {
         // Underlying function for Mul<u64> and MulAssign<u64>
@@ -442,40 +259,20 @@ pub mod grou {
         // Performs the multiplication of a GrouSubset and an u64, and adds the
         // result to the value in self.
         pub fn add_multiply_result<'a>(&mut self, lhs: &GrouSubset<'a>, rhs: u64, offset: usize) {
-            let mut carry = 0u64;
-            let mut megacarry = false;
-
-            for (ind, val) in lhs.data.iter().enumerate() {
-                let effective_ind = offset + ind;
-                // Add another box if lhs.data > self.data
-                while self.len() <= effective_ind {
-                    self.data.push(0);
-                }
-                let (value, tmp_carry) = val.carrying_mul(rhs, carry);
-                let (value, tmp_carry_grou) = self.data[effective_ind].carrying_add(value, false);
-                self.data[effective_ind] = value;
-                if tmp_carry_grou || megacarry {
-                    let (tmp_carry, overflow) =
-                        tmp_carry.carrying_add(tmp_carry_grou as u64, megacarry);
-                    carry = tmp_carry;
-                    megacarry = overflow;
-                } else {
-                    carry = tmp_carry;
-                    megacarry = false;
-                }
+            if lhs.data.is_empty() {
+                return;
             }
-
-            let mut current_index = lhs.len();
-            while carry > 0 || megacarry {
-                if self.len() == current_index {
-                    self.data.push(0);
-                }
-
-                let (value, tmp_carry) = self.data[current_index].carrying_add(carry, megacarry);
-                self.data[current_index] = value;
-                carry = 0;
-                megacarry = tmp_carry;
-                current_index += 1;
+            let end = offset + lhs.len();
+            // Add more blocks if lhs reaches past the end of self.
+            if self.len() < end {
+                self.data.resize(end, 0);
+            }
+            let carry = crate::limbs::addmul_1(&mut self.data[offset..end], lhs.data, rhs);
+            // The carry is left over when it ran past the last block, or
+            // when there are no blocks above lhs to add it to.
+            let carry = crate::limbs::add_1_assign(&mut self.data[end..], carry);
+            if carry != 0 {
+                self.data.push(carry);
             }
         }
 
@@ -486,98 +283,22 @@ pub mod grou {
         }
     }
 
-    impl GrouSubset<'_> {
-        // Straight multiplication of two grou subsets of length
-        // A and B respectively will have A*B operations.
-        fn multiply_straight<'a>(&self, rhs: &GrouSubset<'a>) -> Grou {
-            let mut ret_grou = Grou::empty(self.len() + rhs.len());
-            for (ind, rhs_value) in rhs.data.iter().enumerate() {
-                ret_grou.add_multiply_result(self, *rhs_value, ind);
-            }
-            ret_grou.trim();
-            return ret_grou;
-        }
-    }
-
-    impl std::ops::Mul<&GrouSubset<'_>> for &GrouSubset<'_> {
-        type Output = Grou;
-        fn mul<'a>(self, rhs: &GrouSubset<'a>) -> Grou {
-            self.multiply_straight(rhs)
-        }
-    }
-
-    macro_rules! impl_mul {
-        ($lhs:ty, $rhs:ty) => {
-            impl std::ops::Mul<$rhs> for $lhs {
-                type Output = Grou;
-                fn mul(self, rhs: $rhs) -> Grou {
-                    self.karatsuba_mul(&rhs)
-                }
-            }
-        };
-    }
-
-    impl_mul!(Grou, Grou);
-    impl_mul!(Grou, &Grou);
-    impl_mul!(&Grou, Grou);
-    impl_mul!(&Grou, &Grou);
-
-    // Implementing Karatsuba.
     impl Grou {
-        pub fn karatsuba_mul(&self, rhs: &Grou) -> Grou {
-            // Step 1: Split into GrouSubsets
-            let block_length = calculate_block_length(2, self, rhs);
-            let a0 = self.split_off_block(0, block_length);
-            let a1 = self.split_off_block(a0.len(), block_length);
-            let b0 = rhs.split_off_block(0, block_length);
-            let b1 = rhs.split_off_block(b0.len(), block_length);
-
-            // Make temporary values.
-            let high = &b1 * &a1;
-            let low = &b0 * &a0;
-            let mut t0 = &high + &low;
-
-            let mut t1 = high;
-            t1.move_vec_elements_right(2 * block_length);
-            t1 += low;
-
-            let (sign_a, delta_a) = &a1.sub_with_sign(&a0);
-            let (sign_b, delta_b) = &b1.sub_with_sign(&b0);
-
-            let mut t2 = &delta_a.subset_all() * &delta_b.subset_all();
-
-            t0.move_vec_elements_right(block_length);
-            t2.move_vec_elements_right(block_length);
-
-            //sign = true => addition, subtraction otherwise.
-            let sign = sign_a ^ sign_b;
-            if sign {
-                return t0 + t1 + t2;
-            } else {
-                return (t0 + t1) - t2;
+        /// Raises self to the power exp, by repeated squaring.
+        pub fn pow(&self, exp: u32) -> Grou {
+            let mut result = Grou::from(1);
+            let mut base = self.clone();
+            let mut exp = exp;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = &result * &base;
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = &base * &base;
+                }
             }
+            result
         }
-
-        /*
-        pub fn toom_3_multiplication<'a, 'b>(&'a self, rhs: &'b Grou) -> Grou {
-            let block_length = calculate_block_length(3, self, rhs);
-            let iter_self = BlockIterator::<'a>::new(self, block_length);
-            let iter_rhs = BlockIterator::<'b>::new(rhs, block_length);
-
-            let (a0,a1,a2) = (iter_self.unwrap_next(), iter_self.unwrap_next(), iter_self.unwrap_next());
-            let (b0,b1,b2) = (iter_rhs.unwrap_next(), iter_rhs.unwrap_next(), iter_rhs.unwrap_next());
-            debug_assert!(iter_self.len() == 0 && iter_rhs.len() == 0);
-
-            let w0 = &a0 * &b0;
-            let w4 = &a2 * &b2;
-
-            let t1 = (&a2 + &a1 + &a0) * (&b2 + &b1 + &b0);
-
-            let tm1_a = (&a2 + &a0) - &a1;
-            let tm1_b = (&b2 + &b0) - &b1;
-            let tm1 =  tm1_a * tm1_b;
-
-        }
-        */
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,153 @@
+/// Number of blocks a LimbVec holds before moving them to the heap.
+pub(crate) const INLINE_BLOCKS: usize = 4;
+
+/// The block storage of a Grou.
+///
+/// Up to INLINE_BLOCKS blocks are stored inline, so small numbers never
+/// allocate. Longer numbers spill to a Vec, and stay there when they shrink,
+/// to keep the allocation for later growth. Clones start inline again when
+/// they fit.
+///
+/// It derefs to a slice, and implements the Vec methods the crate uses.
+pub(crate) enum LimbVec {
+    Inline {
+        len: usize,
+        blocks: [u64; INLINE_BLOCKS],
+    },
+    Heap(Vec<u64>),
+}
+
+impl LimbVec {
+    #[inline]
+    pub(crate) fn new() -> LimbVec {
+        LimbVec::Inline {
+            len: 0,
+            blocks: [0; INLINE_BLOCKS],
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> LimbVec {
+        if capacity <= INLINE_BLOCKS {
+            LimbVec::new()
+        } else {
+            LimbVec::Heap(Vec::with_capacity(capacity))
+        }
+    }
+
+    pub(crate) fn from_slice(values: &[u64]) -> LimbVec {
+        if values.len() <= INLINE_BLOCKS {
+            let mut blocks = [0; INLINE_BLOCKS];
+            blocks[..values.len()].copy_from_slice(values);
+            LimbVec::Inline {
+                len: values.len(),
+                blocks,
+            }
+        } else {
+            LimbVec::Heap(values.to_vec())
+        }
+    }
+
+    // Moves inline blocks to the heap, with room for at least the given
+    // number of blocks, and returns the Vec.
+    fn spill(&mut self, capacity: usize) -> &mut Vec<u64> {
+        if let LimbVec::Inline { len, blocks } = self {
+            let mut heap = Vec::with_capacity(std::cmp::max(capacity, 2 * INLINE_BLOCKS));
+            heap.extend_from_slice(&blocks[..*len]);
+            *self = LimbVec::Heap(heap);
+        }
+        match self {
+            LimbVec::Heap(heap) => heap,
+            LimbVec::Inline { .. } => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, value: u64) {
+        match self {
+            LimbVec::Inline { len, blocks } if *len < INLINE_BLOCKS => {
+                blocks[*len] = value;
+                *len += 1;
+            }
+            _ => self.spill(INLINE_BLOCKS + 1).push(value),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn truncate(&mut self, new_len: usize) {
+        match self {
+            LimbVec::Inline { len, .. } => *len = std::cmp::min(*len, new_len),
+            LimbVec::Heap(heap) => heap.truncate(new_len),
+        }
+    }
+
+    pub(crate) fn resize(&mut self, new_len: usize, value: u64) {
+        match self {
+            LimbVec::Inline { len, blocks } if new_len <= INLINE_BLOCKS => {
+                if new_len > *len {
+                    blocks[*len..new_len].fill(value);
+                }
+                *len = new_len;
+            }
+            _ => self.spill(new_len).resize(new_len, value),
+        }
+    }
+}
+
+impl std::ops::Deref for LimbVec {
+    type Target = [u64];
+
+    #[inline]
+    fn deref(&self) -> &[u64] {
+        match self {
+            LimbVec::Inline { len, blocks } => &blocks[..*len],
+            LimbVec::Heap(heap) => heap,
+        }
+    }
+}
+
+impl std::ops::DerefMut for LimbVec {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u64] {
+        match self {
+            LimbVec::Inline { len, blocks } => &mut blocks[..*len],
+            LimbVec::Heap(heap) => heap,
+        }
+    }
+}
+
+impl Clone for LimbVec {
+    fn clone(&self) -> LimbVec {
+        LimbVec::from_slice(self)
+    }
+}
+
+impl Default for LimbVec {
+    fn default() -> LimbVec {
+        LimbVec::new()
+    }
+}
+
+impl From<Vec<u64>> for LimbVec {
+    fn from(values: Vec<u64>) -> LimbVec {
+        if values.len() <= INLINE_BLOCKS {
+            LimbVec::from_slice(&values)
+        } else {
+            LimbVec::Heap(values)
+        }
+    }
+}
+
+impl PartialEq for LimbVec {
+    fn eq(&self, other: &Self) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl Eq for LimbVec {}
+
+impl std::fmt::Debug for LimbVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self[..].fmt(f)
+    }
+}
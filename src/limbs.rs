@@ -0,0 +1,178 @@
+//! Functions on numbers stored as little-endian slices of blocks, in the
+//! style of GMP's mpn layer. None of them allocate. Lengths are not adjusted
+//! for high 0 blocks, and the slices passed together must have the lengths
+//! given in each function's description, which is asserted.
+//!
+//! Rust does not let the output slice alias an input, so the additive
+//! functions also come in _assign forms that update rp in place.
+
+use std::cmp::Ordering;
+
+/// rp = ap + bp, over equal lengths. Returns the carry, 0 or 1.
+pub fn add_n(rp: &mut [u64], ap: &[u64], bp: &[u64]) -> u64 {
+    assert!(rp.len() == ap.len() && ap.len() == bp.len(), "Lengths differ");
+    let mut carry = false;
+    for (r, (a, b)) in rp.iter_mut().zip(ap.iter().zip(bp.iter())) {
+        let (value, tmp_carry) = a.carrying_add(*b, carry);
+        *r = value;
+        carry = tmp_carry;
+    }
+    carry as u64
+}
+
+/// rp += ap, over equal lengths. Returns the carry, 0 or 1.
+pub fn add_n_assign(rp: &mut [u64], ap: &[u64]) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    let mut carry = false;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        let (value, tmp_carry) = r.carrying_add(*a, carry);
+        *r = value;
+        carry = tmp_carry;
+    }
+    carry as u64
+}
+
+/// rp = ap - bp, over equal lengths. Returns the borrow, 0 or 1.
+pub fn sub_n(rp: &mut [u64], ap: &[u64], bp: &[u64]) -> u64 {
+    assert!(rp.len() == ap.len() && ap.len() == bp.len(), "Lengths differ");
+    let mut borrow = false;
+    for (r, (a, b)) in rp.iter_mut().zip(ap.iter().zip(bp.iter())) {
+        let (value, tmp_borrow) = a.borrowing_sub(*b, borrow);
+        *r = value;
+        borrow = tmp_borrow;
+    }
+    borrow as u64
+}
+
+/// rp -= ap, over equal lengths. Returns the borrow, 0 or 1.
+pub fn sub_n_assign(rp: &mut [u64], ap: &[u64]) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    let mut borrow = false;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        let (value, tmp_borrow) = r.borrowing_sub(*a, borrow);
+        *r = value;
+        borrow = tmp_borrow;
+    }
+    borrow as u64
+}
+
+/// rp = ap + b, for a single block b. Returns the carry, 0 or 1.
+pub fn add_1(rp: &mut [u64], ap: &[u64], b: u64) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    rp.copy_from_slice(ap);
+    add_1_assign(rp, b)
+}
+
+/// rp += b, for a single block b. Returns the carry, 0 or 1, or b itself
+/// when rp is empty.
+pub fn add_1_assign(rp: &mut [u64], b: u64) -> u64 {
+    let mut carry = b;
+    for r in rp.iter_mut() {
+        if carry == 0 {
+            break;
+        }
+        let (value, overflow) = r.overflowing_add(carry);
+        *r = value;
+        carry = overflow as u64;
+    }
+    carry
+}
+
+/// rp -= b, for a single block b. Returns the borrow, 0 or 1, or b itself
+/// when rp is empty.
+pub fn sub_1_assign(rp: &mut [u64], b: u64) -> u64 {
+    let mut borrow = b;
+    for r in rp.iter_mut() {
+        if borrow == 0 {
+            break;
+        }
+        let (value, overflow) = r.overflowing_sub(borrow);
+        *r = value;
+        borrow = overflow as u64;
+    }
+    borrow
+}
+
+/// rp = ap * b, for a single block b. Returns the carry block.
+pub fn mul_1(rp: &mut [u64], ap: &[u64], b: u64) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    let mut carry = 0u64;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        let (value, tmp_carry) = a.carrying_mul(b, carry);
+        *r = value;
+        carry = tmp_carry;
+    }
+    carry
+}
+
+/// rp += ap * b, for a single block b. Returns the carry block.
+pub fn addmul_1(rp: &mut [u64], ap: &[u64], b: u64) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    let mut carry = 0u64;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        let (low, high) = a.carrying_mul(b, carry);
+        let (value, overflow) = r.overflowing_add(low);
+        *r = value;
+        carry = high + overflow as u64;
+    }
+    carry
+}
+
+/// rp -= ap * b, for a single block b. Returns the borrow block, which
+/// is what would have to be subtracted from the block above rp.
+pub fn submul_1(rp: &mut [u64], ap: &[u64], b: u64) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    let mut borrow = 0u64;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        let (low, high) = a.carrying_mul(b, borrow);
+        let (value, overflow) = r.overflowing_sub(low);
+        *r = value;
+        borrow = high + overflow as u64;
+    }
+    borrow
+}
+
+/// rp = ap << count, for count < 64. Returns the bits shifted out of the
+/// top block, in the low bits of the result.
+pub fn lshift(rp: &mut [u64], ap: &[u64], count: u32) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    assert!(count < 64, "Shift count must be below 64");
+    if count == 0 {
+        rp.copy_from_slice(ap);
+        return 0;
+    }
+    let mut carry = 0u64;
+    for (r, a) in rp.iter_mut().zip(ap.iter()) {
+        *r = (a << count) | carry;
+        carry = a >> (64 - count);
+    }
+    carry
+}
+
+/// rp = ap >> count, for count < 64. Returns the bits shifted out of the
+/// bottom block, in the high bits of the result.
+pub fn rshift(rp: &mut [u64], ap: &[u64], count: u32) -> u64 {
+    assert_eq!(rp.len(), ap.len(), "Lengths differ");
+    assert!(count < 64, "Shift count must be below 64");
+    if count == 0 {
+        rp.copy_from_slice(ap);
+        return 0;
+    }
+    let mut carry = 0u64;
+    for (r, a) in rp.iter_mut().zip(ap.iter()).rev() {
+        *r = (a >> count) | carry;
+        carry = a << (64 - count);
+    }
+    carry
+}
+
+/// Compares ap and bp, over equal lengths.
+pub fn cmp(ap: &[u64], bp: &[u64]) -> Ordering {
+    assert_eq!(ap.len(), bp.len(), "Lengths differ");
+    for (a, b) in ap.iter().zip(bp.iter()).rev() {
+        if a != b {
+            return a.cmp(b);
+        }
+    }
+    Ordering::Equal
+}
@@ -0,0 +1,270 @@
+use super::grou::Grou;
+
+/// Precomputed values for Montgomery multiplication modulo an odd number.
+///
+/// Values in Montgomery form are represented as x * R mod n, where
+/// R = 2^(64 * k) and k is the number of blocks of the modulus. Products
+/// in that form are reduced with a multiply and a shift instead of a
+/// division, which makes repeated multiplication modulo the same number
+/// (as in modpow) much cheaper.
+#[derive(Clone, Debug)]
+pub struct Montgomery {
+    modulus: Grou,
+    // -modulus^-1 mod 2^64
+    n_prime: u64,
+    // R^2 mod modulus, used to convert into Montgomery form.
+    r2: Grou,
+}
+
+impl Montgomery {
+    /// Returns None if the modulus is even, since R then has no inverse.
+    pub fn new(modulus: &Grou) -> Option<Montgomery> {
+        if modulus.is_even() {
+            return None;
+        }
+        let mut modulus = modulus.clone();
+        modulus.trim();
+
+        // Newton's iteration for the inverse modulo 2^64. Each step doubles
+        // the number of correct low bits, and n * n = 1 mod 8 gives 3.
+        let n0 = modulus.data[0];
+        let mut inverse = n0;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(n0.wrapping_mul(inverse)));
+        }
+
+        let r2 = Grou::from(1).shift_left(128 * modulus.len()) % &modulus;
+        Some(Montgomery {
+            modulus,
+            n_prime: inverse.wrapping_neg(),
+            r2,
+        })
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &Grou {
+        &self.modulus
+    }
+
+    /// Converts x into Montgomery form. x does not need to be reduced.
+    pub fn to_montgomery(&self, x: &Grou) -> Grou {
+        let reduced = x % &self.modulus;
+        self.mul(&reduced, &self.r2)
+    }
+
+    /// Converts x out of Montgomery form.
+    pub fn from_montgomery(&self, x: &Grou) -> Grou {
+        self.mul(x, &Grou::from(1))
+    }
+
+    /// Returns the Montgomery form of 1, which is R mod n.
+    pub fn one(&self) -> Grou {
+        self.to_montgomery(&Grou::from(1))
+    }
+
+    /// Returns a * b / R mod n. Both a and b must be below the modulus.
+    ///
+    /// This is the coarsely integrated operand scanning (CIOS) method, which
+    /// interleaves the multiplication and the reduction block by block.
+    pub fn mul(&self, a: &Grou, b: &Grou) -> Grou {
+        let n = &self.modulus.data[..];
+        let k = n.len();
+        let mut t = vec![0u64; k + 2];
+
+        for i in 0..k {
+            let b_i = b.data.get(i).copied().unwrap_or(0);
+
+            // t += a * b_i
+            let mut carry = 0u64;
+            for (j, t_j) in t.iter_mut().enumerate().take(k) {
+                let a_j = a.data.get(j).copied().unwrap_or(0);
+                let (low, high) = a_j.carrying_mul(b_i, carry);
+                let (value, overflow) = t_j.overflowing_add(low);
+                *t_j = value;
+                carry = high + overflow as u64;
+            }
+            let (value, overflow) = t[k].overflowing_add(carry);
+            t[k] = value;
+            t[k + 1] = overflow as u64;
+
+            // t = (t + m * n) / 2^64, where m makes the low block vanish.
+            let m = t[0].wrapping_mul(self.n_prime);
+            let (low, high) = m.carrying_mul(n[0], 0);
+            let (_, overflow) = t[0].overflowing_add(low);
+            let mut carry = high + overflow as u64;
+            for j in 1..k {
+                let (low, high) = m.carrying_mul(n[j], carry);
+                let (value, overflow) = t[j].overflowing_add(low);
+                t[j - 1] = value;
+                carry = high + overflow as u64;
+            }
+            let (value, overflow) = t[k].overflowing_add(carry);
+            t[k - 1] = value;
+            t[k] = t[k + 1] + overflow as u64;
+        }
+
+        t.truncate(k + 1);
+        let mut result = Grou::from(t);
+        if result >= self.modulus {
+            result = &result - &self.modulus;
+        }
+        result.trim();
+        result
+    }
+
+    /// Returns base^exp mod n. The base and result are in normal form.
+    pub fn pow(&self, base: &Grou, exp: &Grou) -> Grou {
+        let base = self.to_montgomery(base);
+        self.from_montgomery(&self.pow_montgomery(&base, exp))
+    }
+
+    /// Returns base^exp mod n, with the base and result in Montgomery form.
+    pub fn pow_montgomery(&self, base: &Grou, exp: &Grou) -> Grou {
+        let mut result = self.one();
+        for position in (0..exp.bit_length()).rev() {
+            result = self.mul(&result, &result);
+            if exp.bit(position) {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+}
+
+impl Grou {
+    /// Returns self^exp mod modulus. Panics if modulus is 0.
+    ///
+    /// Odd moduli use Montgomery multiplication. Even moduli fall back to
+    /// square-and-multiply with a division after each step.
+    pub fn modpow(&self, exp: &Grou, modulus: &Grou) -> Grou {
+        if modulus.is_zero() {
+            panic!("Division by zero");
+        }
+        if modulus.is_one() {
+            return Grou::from(0);
+        }
+        if let Some(context) = Montgomery::new(modulus) {
+            return context.pow(self, exp);
+        }
+
+        let base = self % modulus;
+        let mut result = Grou::from(1);
+        for position in (0..exp.bit_length()).rev() {
+            result = (&result * &result) % modulus;
+            if exp.bit(position) {
+                result = (&result * &base) % modulus;
+            }
+        }
+        result
+    }
+
+    /// Returns (self + other) mod modulus, for self and other below modulus.
+    pub fn add_mod(&self, other: &Grou, modulus: &Grou) -> Grou {
+        let mut sum = self + other;
+        if &sum >= modulus {
+            sum = &sum - modulus;
+        }
+        sum.trim();
+        sum
+    }
+
+    /// Returns (self - other) mod modulus, for self and other below modulus.
+    pub fn sub_mod(&self, other: &Grou, modulus: &Grou) -> Grou {
+        if self >= other {
+            self - other
+        } else {
+            let mut difference = (self + modulus) - other;
+            difference.trim();
+            difference
+        }
+    }
+}
+
+impl Grou {
+    /// Returns a square root of self modulo the prime p, or None if self is
+    /// not a square modulo p. Of the two roots r and p - r, the smaller one
+    /// is returned. p is assumed to be prime; this is not checked.
+    ///
+    /// p = 3 mod 4 and p = 5 mod 8 have closed forms (Lagrange's and Atkin's
+    /// formulas). Other primes use the Tonelli-Shanks algorithm.
+    pub fn sqrt_mod_prime(&self, p: &Grou) -> Option<Grou> {
+        let a = self % p;
+        if a.is_zero() {
+            return Some(Grou::from(0));
+        }
+        if p == &Grou::from(2) {
+            return Some(a);
+        }
+        if a.legendre(p) != 1 {
+            return None;
+        }
+
+        let mul_mod = |x: &Grou, y: &Grou| (x * y) % p;
+        let root = match p.data[0] & 7 {
+            3 | 7 => {
+                // r = a^((p + 1) / 4)
+                let exp = (p + Grou::from(1)).shift_right(2);
+                a.modpow(&exp, p)
+            }
+            5 => {
+                // v = (2a)^((p - 5) / 8), i = 2a v^2, r = a v (i - 1)
+                let two_a = a.shift_left(1) % p;
+                let v = two_a.modpow(&(p - Grou::from(5)).shift_right(3), p);
+                let i = mul_mod(&two_a, &mul_mod(&v, &v));
+                let i_minus_one = i.sub_mod(&Grou::from(1), p);
+                mul_mod(&mul_mod(&a, &v), &i_minus_one)
+            }
+            _ => tonelli_shanks(&a, p),
+        };
+
+        let other = p - &root;
+        if root <= other {
+            Some(root)
+        } else {
+            Some(other)
+        }
+    }
+}
+
+// Square root of a quadratic residue a modulo an odd prime p.
+fn tonelli_shanks(a: &Grou, p: &Grou) -> Grou {
+    let mul_mod = |x: &Grou, y: &Grou| (x * y) % p;
+    let one = Grou::from(1);
+
+    // p - 1 = q * 2^s, with q odd.
+    let p_minus_one = p - &one;
+    let s = p_minus_one.trailing_zeros();
+    let q = p_minus_one.shift_right(s);
+
+    // Any non-residue z works; half of all numbers are one.
+    let mut z = Grou::from(2);
+    while z.legendre(p) != -1 {
+        z += 1u64;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + &one).shift_right(1)), p);
+
+    // Invariants: c^(2^(m - 1)) = -1, t^(2^(m - 1)) = 1 and r^2 = a t.
+    while !t.is_one() {
+        // Least i with t^(2^i) = 1. It is below m.
+        let mut i = 0;
+        let mut t_power = t.clone();
+        while !t_power.is_one() {
+            t_power = mul_mod(&t_power, &t_power);
+            i += 1;
+        }
+
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b = mul_mod(&b, &b);
+        }
+        m = i;
+        c = mul_mod(&b, &b);
+        t = mul_mod(&t, &c);
+        r = mul_mod(&r, &b);
+    }
+    r
+}
@@ -0,0 +1,174 @@
+use super::limbs;
+
+/// Shorter operands than this use schoolbook multiplication.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Products whose shorter operand has at least this many blocks compute
+/// their subproducts on separate threads, when the `parallel` feature is
+/// enabled and cores are free.
+pub const PARALLEL_THRESHOLD: usize = 1024;
+
+/// Returns the number of scratch blocks mul_into needs to multiply
+/// operands of these lengths, in either order.
+pub fn mul_scratch_len(a_len: usize, b_len: usize) -> usize {
+    let (long, short) = if a_len >= b_len {
+        (a_len, b_len)
+    } else {
+        (b_len, a_len)
+    };
+    if short < KARATSUBA_THRESHOLD {
+        return 0;
+    }
+    let half = long.div_ceil(2);
+    if short <= half {
+        // A product of two chunks, and the space to compute it.
+        return 2 * short + mul_scratch_len(short, short);
+    }
+    // |a0 - a1|, |b0 - b1| and their product, then either the space for
+    // that product or the middle term, which are not needed at once.
+    4 * half + std::cmp::max(2 * half + 1, mul_scratch_len(half, half))
+}
+
+/// Writes a * b into out, which must have exactly a.len() + b.len()
+/// blocks. scratch must have at least mul_scratch_len(a.len(), b.len())
+/// blocks; its contents are overwritten.
+///
+/// All temporary values live in scratch, so this never allocates, and a
+/// buffer sized for the largest product can be reused across calls. The
+/// exception is the `parallel` feature, where subproducts run on other
+/// threads get scratch space of their own.
+/// Karatsuba's method is used once the shorter operand reaches
+/// KARATSUBA_THRESHOLD blocks, and much longer operands are cut into
+/// chunks of the shorter one's length.
+pub fn mul_into(out: &mut [u64], a: &[u64], b: &[u64], scratch: &mut [u64]) {
+    assert_eq!(
+        out.len(),
+        a.len() + b.len(),
+        "Output length must be the sum of the operand lengths"
+    );
+    assert!(
+        scratch.len() >= mul_scratch_len(a.len(), b.len()),
+        "Scratch space is too small"
+    );
+    mul_recursive(out, a, b, scratch);
+}
+
+fn mul_recursive(out: &mut [u64], a: &[u64], b: &[u64], scratch: &mut [u64]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        mul_basecase(out, a, b);
+    } else if b.len() <= a.len().div_ceil(2) {
+        mul_unbalanced(out, a, b, scratch);
+    } else {
+        mul_karatsuba(out, a, b, scratch);
+    }
+}
+
+// Schoolbook multiplication, one row per block of b.
+fn mul_basecase(out: &mut [u64], a: &[u64], b: &[u64]) {
+    out.fill(0);
+    for (ind, b_val) in b.iter().enumerate() {
+        out[ind + a.len()] = limbs::addmul_1(&mut out[ind..ind + a.len()], a, *b_val);
+    }
+}
+
+// a is much longer than b: multiply b by chunks of a of b's length, and
+// add each product in at its offset.
+fn mul_unbalanced(out: &mut [u64], a: &[u64], b: &[u64], scratch: &mut [u64]) {
+    let (product, scratch) = scratch.split_at_mut(2 * b.len());
+    out.fill(0);
+    for (ind, chunk) in a.chunks(b.len()).enumerate() {
+        let product = &mut product[..chunk.len() + b.len()];
+        mul_recursive(product, chunk, b, scratch);
+        let carry = add_assign(&mut out[ind * b.len()..], product);
+        debug_assert!(!carry);
+    }
+}
+
+// With a = a0 + a1 X and b = b0 + b1 X, where X = 2^(64 * half),
+//     a * b = z0 + (z0 + z2 - (a0 - a1)(b0 - b1)) X + z2 X^2,
+// for z0 = a0 b0 and z2 = a1 b1. z0 and z2 go straight into the low and
+// high halves of out, and the middle term is added on top.
+fn mul_karatsuba(out: &mut [u64], a: &[u64], b: &[u64], scratch: &mut [u64]) {
+    let half = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+
+    let (delta_a, scratch) = scratch.split_at_mut(half);
+    let (delta_b, scratch) = scratch.split_at_mut(half);
+    let (z1, scratch) = scratch.split_at_mut(2 * half);
+    let negative_a = abs_diff(delta_a, a0, a1);
+    let negative_b = abs_diff(delta_b, b0, b1);
+
+    let (z0, z2) = out.split_at_mut(2 * half);
+    if b.len() >= PARALLEL_THRESHOLD && crate::parallel::has_spare_thread() {
+        // z0 and z2 get scratch space of their own, so that all three
+        // products can run at once.
+        let mul_alone = |out: &mut [u64], a: &[u64], b: &[u64]| {
+            let mut scratch = vec![0u64; mul_scratch_len(a.len(), b.len())];
+            mul_recursive(out, a, b, &mut scratch);
+        };
+        crate::parallel::join(
+            || mul_alone(z0, a0, b0),
+            || {
+                crate::parallel::join(
+                    || mul_alone(z2, a1, b1),
+                    || mul_recursive(z1, delta_a, delta_b, scratch),
+                )
+            },
+        );
+    } else {
+        mul_recursive(z0, a0, b0, scratch);
+        mul_recursive(z2, a1, b1, scratch);
+        mul_recursive(z1, delta_a, delta_b, scratch);
+    }
+
+    let middle = &mut scratch[..2 * half + 1];
+    middle[..2 * half].copy_from_slice(&out[..2 * half]);
+    middle[2 * half] = 0;
+    add_assign(middle, &out[2 * half..]);
+    if negative_a == negative_b {
+        sub_assign(middle, z1);
+    } else {
+        add_assign(middle, z1);
+    }
+
+    // The middle term fits in the product, so any blocks past the end of
+    // out are 0.
+    let length = std::cmp::min(middle.len(), out.len() - half);
+    debug_assert!(middle[length..].iter().all(|val| *val == 0));
+    let carry = add_assign(&mut out[half..], &middle[..length]);
+    debug_assert!(!carry);
+}
+
+// out += a, where a is not longer than out. The carry runs through the rest
+// of out, and the final carry is returned.
+fn add_assign(out: &mut [u64], a: &[u64]) -> bool {
+    let (low, high) = out.split_at_mut(a.len());
+    let carry = limbs::add_n_assign(low, a);
+    limbs::add_1_assign(high, carry) != 0
+}
+
+// out -= a, where a is not longer than out. Returns the final borrow.
+fn sub_assign(out: &mut [u64], a: &[u64]) -> bool {
+    let (low, high) = out.split_at_mut(a.len());
+    let borrow = limbs::sub_n_assign(low, a);
+    limbs::sub_1_assign(high, borrow) != 0
+}
+
+// Writes |x - y| into out, which has x.len() blocks, and returns whether
+// x < y. y is not longer than x.
+fn abs_diff(out: &mut [u64], x: &[u64], y: &[u64]) -> bool {
+    let (x_low, x_high) = x.split_at(y.len());
+    let y_is_larger =
+        x_high.iter().all(|val| *val == 0) && limbs::cmp(x_low, y) == std::cmp::Ordering::Less;
+    out.fill(0);
+    if y_is_larger {
+        out[..y.len()].copy_from_slice(y);
+        sub_assign(out, x);
+    } else {
+        out.copy_from_slice(x);
+        sub_assign(out, y);
+    }
+    y_is_larger
+}
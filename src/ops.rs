@@ -0,0 +1,287 @@
+use super::grou::{Grou, GrouSubset, GrouSubsetMut};
+use super::limb_vec::LimbVec;
+use super::limbs;
+use std::cmp::Ordering;
+
+/// Access to the blocks of a number, least significant first.
+///
+/// The arithmetic operators of Grou, GrouSubset and GrouSubsetMut take any
+/// AsLimbs type on the right, so views and other block containers mix
+/// freely, as in `&grou + subset` or `subset_mut * &blocks[..]`. Implementing
+/// it for a custom container gives it the same operators on the right-hand
+/// side. The three types also compare with each other by value, as in
+/// `grou < subset`.
+pub trait AsLimbs {
+    fn limbs(&self) -> &[u64];
+}
+
+impl AsLimbs for Grou {
+    #[inline]
+    fn limbs(&self) -> &[u64] {
+        &self.data
+    }
+}
+
+impl AsLimbs for GrouSubset<'_> {
+    #[inline]
+    fn limbs(&self) -> &[u64] {
+        self.data
+    }
+}
+
+impl AsLimbs for GrouSubsetMut<'_> {
+    #[inline]
+    fn limbs(&self) -> &[u64] {
+        self.blocks()
+    }
+}
+
+impl AsLimbs for [u64] {
+    #[inline]
+    fn limbs(&self) -> &[u64] {
+        self
+    }
+}
+
+impl<T: AsLimbs + ?Sized> AsLimbs for &T {
+    #[inline]
+    fn limbs(&self) -> &[u64] {
+        (**self).limbs()
+    }
+}
+
+// The blocks up to the highest nonzero one.
+#[inline]
+pub(crate) fn significant(data: &[u64]) -> &[u64] {
+    let length = data
+        .iter()
+        .rposition(|val| *val != 0)
+        .map_or(0, |ind| ind + 1);
+    &data[..length]
+}
+
+// Compares two numbers, ignoring high 0 blocks.
+pub(crate) fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    let a = significant(a);
+    let b = significant(b);
+    a.len().cmp(&b.len()).then_with(|| limbs::cmp(a, b))
+}
+
+// The sum has the length of the longer operand, plus one block if there
+// is a final carry.
+pub(crate) fn add_limbs(a: &[u64], b: &[u64]) -> Grou {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Grou {
+        data: LimbVec::with_capacity(long.len() + 1),
+    };
+    result.data.resize(long.len(), 0);
+    result.data.copy_from_slice(long);
+    add_assign_limbs(&mut result, short);
+    result
+}
+
+// Adds in place. The blocks only grow when other is longer, or when the
+// final carry needs a new block.
+pub(crate) fn add_assign_limbs(g: &mut Grou, other: &[u64]) {
+    if g.len() < other.len() {
+        g.data.resize(other.len(), 0);
+    }
+    let (low, high) = g.data.split_at_mut(other.len());
+    let carry = limbs::add_n_assign(low, other);
+    if limbs::add_1_assign(high, carry) != 0 {
+        g.data.push(1);
+    }
+}
+
+// Panics if b > a.
+pub(crate) fn sub_limbs(a: &[u64], b: &[u64]) -> Grou {
+    match cmp_limbs(a, b) {
+        Ordering::Less => panic!("Subtraction leads to underflow"),
+        Ordering::Equal => Grou::from(0),
+        Ordering::Greater => sub_limbs_unchecked(a, b),
+    }
+}
+
+// Expects a >= b.
+fn sub_limbs_unchecked(a: &[u64], b: &[u64]) -> Grou {
+    let a = significant(a);
+    let b = significant(b);
+    let mut result = Grou {
+        data: LimbVec::from_slice(a),
+    };
+    let (low, high) = result.data.split_at_mut(b.len());
+    let borrow = limbs::sub_n_assign(low, b);
+    limbs::sub_1_assign(high, borrow);
+    result.trim();
+    result
+}
+
+// Returns |a - b|, along with a flag that is true when a > b.
+pub(crate) fn sub_with_sign_limbs(a: &[u64], b: &[u64]) -> (bool, Grou) {
+    match cmp_limbs(a, b) {
+        Ordering::Less => (false, sub_limbs_unchecked(b, a)),
+        Ordering::Equal => (false, Grou::from(0)),
+        Ordering::Greater => (true, sub_limbs_unchecked(a, b)),
+    }
+}
+
+// Long balanced operands go to the Toom schemes, and the rest to the
+// Karatsuba kernels.
+pub(crate) fn mul_limbs(a: &[u64], b: &[u64]) -> Grou {
+    let a = significant(a);
+    let b = significant(b);
+    match crate::toom::select(a.len(), b.len()) {
+        Some(scheme) => scheme.mul(a, b),
+        None => mul_karatsuba_limbs(a, b),
+    }
+}
+
+// The product is computed in one buffer together with the scratch space,
+// then copied to a result of its exact length, so the result does not hold
+// on to the scratch space.
+pub(crate) fn mul_karatsuba_limbs(a: &[u64], b: &[u64]) -> Grou {
+    let a = significant(a);
+    let b = significant(b);
+    if a.is_empty() || b.is_empty() {
+        return Grou::from(0);
+    }
+
+    let length = a.len() + b.len();
+    let mut blocks = vec![0u64; length + crate::mul::mul_scratch_len(a.len(), b.len())];
+    let (out, scratch) = blocks.split_at_mut(length);
+    crate::mul::mul_into(out, a, b, scratch);
+    Grou {
+        data: LimbVec::from_slice(significant(out)),
+    }
+}
+
+impl std::cmp::PartialOrd for Grou {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(cmp_limbs(&self.data, &other.data))
+    }
+}
+
+impl std::cmp::PartialOrd for GrouSubset<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(cmp_limbs(self.data, other.data))
+    }
+}
+
+// Comparisons between different types go by value, so high zero blocks in a
+// view do not matter.
+macro_rules! impl_cmp_as_limbs {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::cmp::PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                cmp_limbs(self.limbs(), other.limbs()) == Ordering::Equal
+            }
+        }
+
+        impl std::cmp::PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                Some(cmp_limbs(self.limbs(), other.limbs()))
+            }
+        }
+    };
+}
+
+impl_cmp_as_limbs!(Grou, GrouSubset<'_>);
+impl_cmp_as_limbs!(GrouSubset<'_>, Grou);
+impl_cmp_as_limbs!(Grou, GrouSubsetMut<'_>);
+impl_cmp_as_limbs!(GrouSubsetMut<'_>, Grou);
+impl_cmp_as_limbs!(GrouSubset<'_>, GrouSubsetMut<'_>);
+impl_cmp_as_limbs!(GrouSubsetMut<'_>, GrouSubset<'_>);
+
+macro_rules! impl_ops_as_limbs {
+    ($lhs: ty) => {
+        impl<R: AsLimbs> std::ops::Add<R> for $lhs {
+            type Output = Grou;
+            fn add(self, other: R) -> Grou {
+                add_limbs(self.limbs(), other.limbs())
+            }
+        }
+
+        impl<R: AsLimbs> std::ops::Sub<R> for $lhs {
+            type Output = Grou;
+            fn sub(self, other: R) -> Grou {
+                sub_limbs(self.limbs(), other.limbs())
+            }
+        }
+
+        impl<R: AsLimbs> std::ops::Mul<R> for $lhs {
+            type Output = Grou;
+            fn mul(self, other: R) -> Grou {
+                mul_limbs(self.limbs(), other.limbs())
+            }
+        }
+
+        impl<R: AsLimbs> std::ops::Div<R> for $lhs {
+            type Output = Grou;
+            fn div(self, other: R) -> Grou {
+                crate::division::div_rem_limbs(self.limbs(), other.limbs()).0
+            }
+        }
+
+        impl<R: AsLimbs> std::ops::Rem<R> for $lhs {
+            type Output = Grou;
+            fn rem(self, other: R) -> Grou {
+                crate::division::div_rem_limbs(self.limbs(), other.limbs()).1
+            }
+        }
+    };
+}
+
+impl_ops_as_limbs!(Grou);
+impl_ops_as_limbs!(&Grou);
+impl_ops_as_limbs!(GrouSubset<'_>);
+impl_ops_as_limbs!(&GrouSubset<'_>);
+impl_ops_as_limbs!(GrouSubsetMut<'_>);
+impl_ops_as_limbs!(&GrouSubsetMut<'_>);
+
+impl<R: AsLimbs> std::ops::AddAssign<R> for Grou {
+    fn add_assign(&mut self, other: R) {
+        add_assign_limbs(self, other.limbs());
+    }
+}
+
+/// Subtracts in place. Panics if other is larger than self.
+impl<R: AsLimbs> std::ops::SubAssign<R> for Grou {
+    fn sub_assign(&mut self, other: R) {
+        let other = significant(other.limbs());
+        if cmp_limbs(&self.data, other) == Ordering::Less {
+            panic!("Subtraction leads to underflow");
+        }
+        let (low, high) = self.data.split_at_mut(other.len());
+        let borrow = limbs::sub_n_assign(low, other);
+        limbs::sub_1_assign(high, borrow);
+        self.trim();
+    }
+}
+
+impl<R: AsLimbs> std::ops::MulAssign<R> for Grou {
+    fn mul_assign(&mut self, other: R) {
+        *self = mul_limbs(&self.data, other.limbs());
+    }
+}
+
+impl Grou {
+    /// Returns |self - other|, along with a flag that is true when
+    /// self > other.
+    pub fn sub_with_sign<R: AsLimbs + ?Sized>(&self, other: &R) -> (bool, Grou) {
+        sub_with_sign_limbs(&self.data, other.limbs())
+    }
+
+    /// Multiplies with the Karatsuba kernels in the mul module, which `*`
+    /// also uses below the Toom thresholds.
+    pub fn karatsuba_mul<R: AsLimbs + ?Sized>(&self, rhs: &R) -> Grou {
+        mul_karatsuba_limbs(&self.data, rhs.limbs())
+    }
+}
+
+impl GrouSubset<'_> {
+    /// Returns |self - other|, along with a flag that is true when
+    /// self > other.
+    pub fn sub_with_sign<R: AsLimbs + ?Sized>(&self, other: &R) -> (bool, Grou) {
+        sub_with_sign_limbs(self.data, other.limbs())
+    }
+}
@@ -0,0 +1,115 @@
+// Runs independent subproducts of a multiplication at once, with the
+// `parallel` feature. Without it, everything here runs in order on the
+// calling thread. The work is split the same way either way, so the results
+// are identical.
+
+/// Runs a and b, possibly at the same time, and returns both results.
+#[cfg(feature = "parallel")]
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    let guard = match threads::reserve() {
+        Some(guard) => guard,
+        None => return (a(), b()),
+    };
+    std::thread::scope(|scope| {
+        let handle = scope.spawn(move || {
+            let _guard = guard;
+            a()
+        });
+        let rb = b();
+        match handle.join() {
+            Ok(ra) => (ra, rb),
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    })
+}
+
+/// Runs a and b, possibly at the same time, and returns both results.
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    (a(), b())
+}
+
+/// Returns whether join could currently run its closures at once. Callers
+/// use it to skip setting up for parallel work that would not happen.
+#[cfg(feature = "parallel")]
+pub(crate) fn has_spare_thread() -> bool {
+    threads::has_spare()
+}
+
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub(crate) fn has_spare_thread() -> bool {
+    false
+}
+
+/// Applies f to every item, possibly at the same time, and returns the
+/// results in order.
+pub(crate) fn map<T, R, F>(items: &[T], f: &F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    match items.len() {
+        0 => Vec::new(),
+        1 => vec![f(&items[0])],
+        length => {
+            let (low, high) = items.split_at(length / 2);
+            let (mut low, high) = join(|| map(low, f), || map(high, f));
+            low.extend(high);
+            low
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+mod threads {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::OnceLock;
+
+    // Threads started by join that are still running. The calling thread
+    // does its share of the work, so one core is left for it.
+    static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+    fn limit() -> usize {
+        static LIMIT: OnceLock<usize> = OnceLock::new();
+        *LIMIT
+            .get_or_init(|| std::thread::available_parallelism().map_or(0, |cores| cores.get() - 1))
+    }
+
+    pub(super) fn has_spare() -> bool {
+        RUNNING.load(Ordering::Acquire) < limit()
+    }
+
+    pub(super) struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            RUNNING.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+
+    // Takes one of the free threads, if there is one. It is given back when
+    // the guard is dropped, even if the work panics.
+    pub(super) fn reserve() -> Option<Guard> {
+        RUNNING
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < limit()).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Guard)
+    }
+}
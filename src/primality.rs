@@ -0,0 +1,319 @@
+use super::grou::Grou;
+use super::modular::Montgomery;
+use super::random::RngSource;
+use super::sieve::SMALL_PRIMES;
+
+// Number of odd candidates sieved at once when searching for primes.
+const SIEVE_WINDOW: usize = 2048;
+
+impl Grou {
+    /// Miller-Rabin test, with one round for each of the given bases.
+    ///
+    /// Returns false if self is composite. If it returns true, self is either
+    /// prime or a strong pseudoprime to every base. Bases that are 0, 1 or -1
+    /// modulo self say nothing and are skipped.
+    pub fn is_probable_prime(&self, bases: &[u64]) -> bool {
+        if let Some(result) = trial_division(self) {
+            return result;
+        }
+
+        let context = Montgomery::new(self).unwrap();
+        bases
+            .iter()
+            .all(|base| is_strong_probable_prime(&context, &Grou::from(*base)))
+    }
+
+    /// Baillie-PSW test: a strong base 2 Miller-Rabin test followed by a
+    /// strong Lucas test with Selfridge's parameters.
+    ///
+    /// No composite passing both tests is known, and there are none below
+    /// 2^64, so this is deterministic in that range.
+    pub fn is_prime_bpsw(&self) -> bool {
+        if let Some(result) = trial_division(self) {
+            return result;
+        }
+
+        let context = Montgomery::new(self).unwrap();
+        if !is_strong_probable_prime(&context, &Grou::from(2)) {
+            return false;
+        }
+
+        // Selfridge's method never finds a D for squares.
+        if self.is_perfect_square() {
+            return false;
+        }
+        is_strong_lucas_probable_prime(&context)
+    }
+}
+
+impl Grou {
+    /// Returns the smallest prime strictly greater than self.
+    pub fn next_prime(&self) -> Grou {
+        if self < &Grou::from(2) {
+            return Grou::from(2);
+        }
+
+        let mut start = self + Grou::from(if self.is_even() { 1 } else { 2 });
+        loop {
+            if let Some(prime) = search_window(&start, true, None) {
+                return prime;
+            }
+            start += Grou::from(2 * SIEVE_WINDOW as u64);
+        }
+    }
+
+    /// Returns the largest prime strictly below self, or None if there is
+    /// none, i.e. if self <= 2.
+    pub fn prev_prime(&self) -> Option<Grou> {
+        if self <= &Grou::from(2) {
+            return None;
+        }
+        if self == &Grou::from(3) {
+            return Some(Grou::from(2));
+        }
+
+        let mut start = self - Grou::from(if self.is_even() { 1 } else { 2 });
+        loop {
+            if let Some(prime) = search_window(&start, false, None) {
+                return Some(prime);
+            }
+            if start < Grou::from(2 * SIEVE_WINDOW as u64 + 3) {
+                // Every odd number down to 3 has been tried.
+                return Some(Grou::from(2));
+            }
+            start = &start - Grou::from(2 * SIEVE_WINDOW as u64);
+        }
+    }
+
+    /// Returns a random prime with exactly `bits` bits. Panics if bits < 2.
+    ///
+    /// Each attempt picks a random odd starting point and sieves a window
+    /// of candidates above it, so only the survivors get a full BPSW test.
+    pub fn random_prime(bits: usize, rng: &mut impl RngSource) -> Grou {
+        if bits < 2 {
+            panic!("There are no primes with fewer than 2 bits");
+        }
+        if bits == 2 {
+            return Grou::from(2 + (rng.next_u64() & 1));
+        }
+
+        let limit = Grou::from(1).shift_left(bits);
+        loop {
+            let mut start = Grou::random_bits(bits - 1, rng) + Grou::from(1).shift_left(bits - 1);
+            start.data[0] |= 1;
+            if let Some(prime) = search_window(&start, true, Some(&limit)) {
+                return prime;
+            }
+        }
+    }
+}
+
+// Tests the odd candidates start + 2i (or start - 2i when searching
+// downward) for i < SIEVE_WINDOW in order, and returns the first prime.
+// Candidates with a factor below 1000 are sieved out beforehand. Stops
+// early at the limit, or at 3 when searching downward.
+fn search_window(start: &Grou, upward: bool, limit: Option<&Grou>) -> Option<Grou> {
+    let candidate = |i: usize| {
+        let offset = Grou::from(2 * i as u64);
+        if upward {
+            start + offset
+        } else {
+            start - offset
+        }
+    };
+
+    // The sieve would reject the small primes themselves, so small windows
+    // are tested directly.
+    if start.bit_length() <= 20 {
+        for i in 0..SIEVE_WINDOW {
+            if !upward && start < &Grou::from(2 * i as u64 + 3) {
+                return None;
+            }
+            let n = candidate(i);
+            if limit.is_some_and(|limit| &n >= limit) {
+                return None;
+            }
+            if n.is_prime_bpsw() {
+                return Some(n);
+            }
+        }
+        return None;
+    }
+
+    let mut composite = [false; SIEVE_WINDOW];
+    for &p in SMALL_PRIMES[1..].iter() {
+        // Solve start +- 2i = 0 mod p for i, using 2^-1 = p / 2 + 1.
+        let r = start.rem_small(p);
+        let target = if upward { (p - r) % p } else { r };
+        let mut i = (target * (p / 2 + 1) % p) as usize;
+        while i < SIEVE_WINDOW {
+            composite[i] = true;
+            i += p as usize;
+        }
+    }
+
+    for (i, is_composite) in composite.iter().enumerate() {
+        if *is_composite {
+            continue;
+        }
+        let n = candidate(i);
+        if limit.is_some_and(|limit| &n >= limit) {
+            return None;
+        }
+        if n.is_prime_bpsw() {
+            return Some(n);
+        }
+    }
+    None
+}
+
+// Settles small numbers and numbers with a small factor. Returns None when
+// the number is odd, has no factor below 1000 and is too large to
+// conclude from that.
+fn trial_division(n: &Grou) -> Option<bool> {
+    if n < &Grou::from(1000) {
+        let small = n.data.first().copied().unwrap_or(0);
+        return Some(SMALL_PRIMES.contains(&small));
+    }
+
+    // Take the remainder modulo a product of several primes at once, so
+    // that only one pass over the number is needed per group.
+    let mut start = 0;
+    while start < SMALL_PRIMES.len() {
+        let mut product = 1u64;
+        let mut end = start;
+        while end < SMALL_PRIMES.len() {
+            match product.checked_mul(SMALL_PRIMES[end]) {
+                Some(next) => product = next,
+                None => break,
+            }
+            end += 1;
+        }
+
+        let remainder = n.rem_small(product);
+        if SMALL_PRIMES[start..end].iter().any(|p| remainder.is_multiple_of(*p)) {
+            return Some(false);
+        }
+        start = end;
+    }
+
+    // Any composite below 1009^2 has a prime factor below 1009.
+    if n < &Grou::from(1009 * 1009) {
+        return Some(true);
+    }
+    None
+}
+
+fn is_strong_probable_prime(context: &Montgomery, base: &Grou) -> bool {
+    let n = context.modulus();
+    let n_minus_one = n - Grou::from(1);
+    let base = base % n;
+    if base.is_zero() || base.is_one() || base == n_minus_one {
+        return true;
+    }
+
+    // n - 1 = d * 2^s, with d odd.
+    let s = n_minus_one.trailing_zeros();
+    let d = n_minus_one.shift_right(s);
+
+    let one = context.one();
+    let minus_one = n - &one;
+    let mut x = context.pow_montgomery(&context.to_montgomery(&base), &d);
+    if x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = context.mul(&x, &x);
+        if x == minus_one {
+            return true;
+        }
+        if x == one {
+            return false;
+        }
+    }
+    false
+}
+
+fn is_strong_lucas_probable_prime(context: &Montgomery) -> bool {
+    let n = context.modulus();
+
+    // Selfridge's method A: the first D in 5, -7, 9, -11, ... with
+    // (D/n) = -1, P = 1 and Q = (1 - D) / 4.
+    let mut d = 5i64;
+    loop {
+        match jacobi_small(d, n) {
+            -1 => break,
+            0 => return false,
+            _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+        }
+    }
+    let q = (1 - d) / 4;
+
+    let zero = Grou::from(0);
+    let d_m = signed_to_montgomery(context, d);
+    let q_m = signed_to_montgomery(context, q);
+
+    // n + 1 = k * 2^s, with k odd.
+    let n_plus_one = n + Grou::from(1);
+    let s = n_plus_one.trailing_zeros();
+    let k = n_plus_one.shift_right(s);
+
+    // Left to right binary method for U_k and V_k, with P = 1.
+    let mut u = context.one();
+    let mut v = context.one();
+    let mut q_k = q_m.clone();
+    for position in (0..k.bit_length() - 1).rev() {
+        u = context.mul(&u, &v);
+        v = context.mul(&v, &v).sub_mod(&q_k.add_mod(&q_k, n), n);
+        q_k = context.mul(&q_k, &q_k);
+
+        if k.bit(position) {
+            let new_u = half_mod(&u.add_mod(&v, n), n);
+            let new_v = half_mod(&context.mul(&d_m, &u).add_mod(&v, n), n);
+            u = new_u;
+            v = new_v;
+            q_k = context.mul(&q_k, &q_m);
+        }
+    }
+
+    if u == zero || v == zero {
+        return true;
+    }
+    for _ in 1..s {
+        v = context.mul(&v, &v).sub_mod(&q_k.add_mod(&q_k, n), n);
+        if v == zero {
+            return true;
+        }
+        q_k = context.mul(&q_k, &q_k);
+    }
+    false
+}
+
+fn signed_to_montgomery(context: &Montgomery, value: i64) -> Grou {
+    let magnitude = context.to_montgomery(&Grou::from(value.unsigned_abs()));
+    if value < 0 {
+        Grou::from(0).sub_mod(&magnitude, context.modulus())
+    } else {
+        magnitude
+    }
+}
+
+// x / 2 mod n, for odd n.
+fn half_mod(x: &Grou, n: &Grou) -> Grou {
+    if x.is_even() {
+        x.shift_right(1)
+    } else {
+        (x + n).shift_right(1)
+    }
+}
+
+// Jacobi symbol (a/n) for a small signed a and an odd n.
+fn jacobi_small(a: i64, n: &Grou) -> i8 {
+    let symbol = Grou::from(a.unsigned_abs()).jacobi(n);
+    // (-1/n) = -1 iff n = 3 mod 4.
+    if a < 0 && n.data[0] & 3 == 3 {
+        -symbol
+    } else {
+        symbol
+    }
+}
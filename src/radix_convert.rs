@@ -1,4 +1,5 @@
 use super::grou::Grou;
+use std::cell::OnceCell;
 
 // The base is the largest number of the form (base)^N <= 2^64
 // where N is an integer.
@@ -135,4 +136,124 @@ pub fn convert_to_binary(num_in_base: &[u64], base :u64) -> Grou {
     }
 
     return ret_grou;
-}
\ No newline at end of file
+}
+
+// Numbers of at most this many blocks are converted to decimal by repeated
+// division by 10^19. Larger ones are first split in two.
+const DECIMAL_SPLIT_THRESHOLD: usize = 32;
+
+// Powers of 10 with fewer blocks than this are divided by with long
+// division, which is faster than computing their reciprocals.
+const DECIMAL_RECIPROCAL_THRESHOLD: usize = 128;
+
+/// Converts a Grou to its decimal representation.
+///
+/// Large numbers are split by divide and conquer: dividing by 10^(19 * 2^k),
+/// with k chosen so the quotient and the remainder have similar sizes, and
+/// converting both halves recursively. The powers of 10 are computed once by
+/// repeated squaring, along with their reciprocals, so each split is a few
+/// multiplications rather than a long division. This makes the conversion
+/// subquadratic, at O(M(n) log n) for the cost M(n) of a multiplication.
+pub fn convert_to_decimal_string(g: &Grou) -> String {
+    let mut g = g.clone();
+    g.trim();
+    let mut powers = vec![DecimalPower::new(Grou::from(BASE_DECIMAL), g.len())];
+    loop {
+        let last = &powers[powers.len() - 1].value;
+        if 2 * last.len() > g.len() {
+            break;
+        }
+        let mut square = last * last;
+        square.trim();
+        powers.push(DecimalPower::new(square, g.len()));
+    }
+
+    let mut s = String::new();
+    write_decimal(&g, &powers, 0, &mut s);
+    s
+}
+
+// A power of 10 to divide by, with floor(2^bits / value) for dividing by
+// it through multiplication. The reciprocal is only computed once needed,
+// as the largest power is often never used.
+struct DecimalPower {
+    value: Grou,
+    inverse: OnceCell<Grou>,
+    bits: usize,
+}
+
+impl DecimalPower {
+    // write_decimal only divides parts of the number it was given, with
+    // less than four times the blocks of the power.
+    fn new(value: Grou, limit: usize) -> DecimalPower {
+        let bits = 64 * limit.min(4 * value.len());
+        DecimalPower {
+            value,
+            inverse: OnceCell::new(),
+            bits,
+        }
+    }
+
+    fn div_rem(&self, x: &Grou) -> (Grou, Grou) {
+        if self.value.len() < DECIMAL_RECIPROCAL_THRESHOLD {
+            return x.div_rem(&self.value);
+        }
+        let inverse = self
+            .inverse
+            .get_or_init(|| crate::division::reciprocal(&self.value, self.bits));
+        crate::division::div_rem_with_reciprocal(x, &self.value, inverse, self.bits)
+    }
+}
+
+// Appends the digits of x, padded with zeros to the width. A width of 0
+// means no padding, which is used for the leading part.
+// powers[k] holds 10^(19 * 2^k).
+fn write_decimal(x: &Grou, powers: &[DecimalPower], width: usize, s: &mut String) {
+    if x.len() <= DECIMAL_SPLIT_THRESHOLD {
+        let digits = convert_to_decimal_string_small(x);
+        if digits.len() < width {
+            s.push_str(&"0".repeat(width - digits.len()));
+        }
+        s.push_str(&digits);
+        return;
+    }
+
+    // The largest power that has at most half the blocks of x.
+    let level = powers
+        .iter()
+        .rposition(|power| 2 * power.value.len() <= x.len() + 1)
+        .unwrap();
+    let low_width = 19 << level;
+    let (high, low) = powers[level].div_rem(x);
+    write_decimal(&high, powers, width.saturating_sub(low_width), s);
+    write_decimal(&low, powers, low_width, s);
+}
+
+// Converts by repeatedly dividing by the decimal base 10^19 and printing
+// each remainder as 19 digits.
+fn convert_to_decimal_string_small(g: &Grou) -> String {
+    let mut packets = Vec::<u64>::new();
+    let mut remaining = g.clone();
+    while !remaining.is_zero() {
+        let (quotient, remainder) = remaining.div_rem_small(BASE_DECIMAL);
+        packets.push(remainder);
+        remaining = quotient;
+    }
+
+    match packets.split_last() {
+        None => String::from("0"),
+        Some((first, rest)) => {
+            let mut s = first.to_string();
+            for packet in rest.iter().rev() {
+                s.push_str(&format!("{:019}", packet));
+            }
+            s
+        }
+    }
+}
+
+impl std::fmt::Display for Grou {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad_integral(true, "", &convert_to_decimal_string(self))
+    }
+}
@@ -0,0 +1,64 @@
+use super::grou::Grou;
+
+/// A source of random 64 bit blocks.
+///
+/// This keeps the crate free of dependencies: anything that can produce
+/// uniformly distributed u64s can be used to generate random numbers.
+/// With the `rand` feature, every `rand::RngCore` is a RngSource.
+pub trait RngSource {
+    fn next_u64(&mut self) -> u64;
+}
+
+#[cfg(feature = "rand")]
+impl<R: rand::RngCore + ?Sized> RngSource for R {
+    fn next_u64(&mut self) -> u64 {
+        rand::RngCore::next_u64(self)
+    }
+}
+
+impl Grou {
+    /// Returns a uniformly distributed number below 2^bits.
+    pub fn random_bits(bits: usize, rng: &mut impl RngSource) -> Grou {
+        let blocks = bits.div_ceil(64);
+        let mut data: Vec<u64> = (0..blocks).map(|_| rng.next_u64()).collect();
+        if !bits.is_multiple_of(64) {
+            data[blocks - 1] &= (1u64 << (bits % 64)) - 1;
+        }
+
+        let mut g = Grou::from(data);
+        g.trim();
+        if g.is_empty() {
+            return Grou::from(0);
+        }
+        g
+    }
+
+    /// Returns a uniformly distributed number in [0, bound). Panics if
+    /// bound is 0.
+    ///
+    /// Draws just enough bits to cover bound - 1 and rejects draws that are
+    /// too large, which takes fewer than 2 draws on average.
+    pub fn random_below(bound: &Grou, rng: &mut impl RngSource) -> Grou {
+        if bound.is_zero() {
+            panic!("Cannot sample below 0");
+        }
+        let bits = (bound - Grou::from(1)).bit_length();
+        loop {
+            let candidate = Grou::random_bits(bits, rng);
+            if &candidate < bound {
+                return candidate;
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed number in [low, high). Panics if the
+    /// range is empty.
+    pub fn random_range(low: &Grou, high: &Grou, rng: &mut impl RngSource) -> Grou {
+        if low >= high {
+            panic!("Cannot sample from an empty range");
+        }
+        let mut result = low + Grou::random_below(&(high - low), rng);
+        result.trim();
+        result
+    }
+}
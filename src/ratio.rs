@@ -0,0 +1,203 @@
+use super::grou::Grou;
+use super::signed::GrouCoeff;
+
+/// An exact fraction num / den, always kept in lowest terms with a positive
+/// denominator, so that equal fractions have equal representations.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GrouRatio {
+    num: GrouCoeff,
+    den: Grou,
+}
+
+impl GrouRatio {
+    /// Returns num / den in lowest terms. Panics if den is 0.
+    pub fn new(num: GrouCoeff, den: Grou) -> GrouRatio {
+        if den.is_zero() {
+            panic!("Denominator is 0");
+        }
+        let (negative, magnitude) = num.into_parts();
+        let divisor = magnitude.gcd(&den);
+        if divisor.is_zero() || divisor.is_one() {
+            let mut den = den;
+            den.trim();
+            return GrouRatio {
+                num: GrouCoeff::new(negative, magnitude),
+                den,
+            };
+        }
+        GrouRatio {
+            num: GrouCoeff::new(negative, &magnitude / &divisor),
+            den: &den / &divisor,
+        }
+    }
+
+    #[inline]
+    pub fn numer(&self) -> &GrouCoeff {
+        &self.num
+    }
+
+    #[inline]
+    pub fn denom(&self) -> &Grou {
+        &self.den
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.den.is_one()
+    }
+
+    /// Returns the largest integer <= self.
+    pub fn floor(&self) -> GrouCoeff {
+        let (quotient, remainder) = self.num.magnitude().div_rem(&self.den);
+        if self.num.is_negative() && !remainder.is_zero() {
+            GrouCoeff::new(true, quotient + Grou::from(1))
+        } else {
+            GrouCoeff::new(self.num.is_negative(), quotient)
+        }
+    }
+
+    /// Returns the smallest integer >= self.
+    pub fn ceil(&self) -> GrouCoeff {
+        -(-self).floor()
+    }
+
+    /// Returns the nearest integer, with ties rounded away from 0.
+    pub fn round(&self) -> GrouCoeff {
+        // |num| / den + 1/2, rounded down, is the rounded magnitude.
+        let doubled = self.num.magnitude().shift_left(1) + &self.den;
+        let magnitude = doubled / self.den.shift_left(1);
+        GrouCoeff::new(self.num.is_negative(), magnitude)
+    }
+
+    /// Returns 1 / self. Panics if self is 0.
+    pub fn recip(&self) -> GrouRatio {
+        let (negative, magnitude) = self.num.clone().into_parts();
+        GrouRatio::new(GrouCoeff::new(negative, self.den.clone()), magnitude)
+    }
+}
+
+impl From<GrouCoeff> for GrouRatio {
+    fn from(num: GrouCoeff) -> GrouRatio {
+        GrouRatio {
+            num,
+            den: Grou::from(1),
+        }
+    }
+}
+
+impl From<Grou> for GrouRatio {
+    fn from(num: Grou) -> GrouRatio {
+        GrouRatio::from(GrouCoeff::from(num))
+    }
+}
+
+impl From<i64> for GrouRatio {
+    fn from(num: i64) -> GrouRatio {
+        GrouRatio::from(GrouCoeff::from(num))
+    }
+}
+
+/// Parses a fraction "a/b", an integer "a" or a decimal "a.b", each with an
+/// optional leading '-'. The integer parts accept the same formats as Grou,
+/// except in decimals, whose parts are both plain decimal digits.
+impl std::convert::From<&str> for GrouRatio {
+    fn from(s: &str) -> GrouRatio {
+        if let Some((num, den)) = s.split_once('/') {
+            return GrouRatio::new(GrouCoeff::from(num), Grou::from(den));
+        }
+        if let Some((whole, fraction)) = s.split_once('.') {
+            let unsigned = whole.strip_prefix('-').unwrap_or(whole);
+            let is_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+            if unsigned.is_empty() || !is_digits(unsigned) || !is_digits(fraction) {
+                panic!("Input format is invalid.")
+            }
+            // "-1.25" is -125 / 100.
+            let mut digits = String::from(whole);
+            digits.push_str(fraction);
+            let den = Grou::from(10).pow(fraction.len() as u32);
+            return GrouRatio::new(GrouCoeff::from(&digits[..]), den);
+        }
+        GrouRatio::from(GrouCoeff::from(s))
+    }
+}
+
+impl std::fmt::Display for GrouRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+impl std::ops::Neg for GrouRatio {
+    type Output = GrouRatio;
+    fn neg(self) -> GrouRatio {
+        GrouRatio {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl std::ops::Neg for &GrouRatio {
+    type Output = GrouRatio;
+    fn neg(self) -> GrouRatio {
+        GrouRatio {
+            num: -&self.num,
+            den: self.den.clone(),
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for GrouRatio {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        // Denominators are positive, so cross multiplying keeps the order.
+        let lhs = &self.num * &other.den;
+        let rhs = &other.num * &self.den;
+        lhs.partial_cmp(&rhs)
+    }
+}
+
+macro_rules! impl_ops_ratio {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::ops::Add<$rhs> for $lhs {
+            type Output = GrouRatio;
+            fn add(self, other: $rhs) -> GrouRatio {
+                let num = &self.num * &other.den + &other.num * &self.den;
+                GrouRatio::new(num, &self.den * &other.den)
+            }
+        }
+
+        impl std::ops::Sub<$rhs> for $lhs {
+            type Output = GrouRatio;
+            fn sub(self, other: $rhs) -> GrouRatio {
+                let num = &self.num * &other.den - &other.num * &self.den;
+                GrouRatio::new(num, &self.den * &other.den)
+            }
+        }
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = GrouRatio;
+            fn mul(self, other: $rhs) -> GrouRatio {
+                GrouRatio::new(&self.num * &other.num, &self.den * &other.den)
+            }
+        }
+
+        impl std::ops::Div<$rhs> for $lhs {
+            type Output = GrouRatio;
+            fn div(self, other: $rhs) -> GrouRatio {
+                if other.num.is_zero() {
+                    panic!("Division by zero");
+                }
+                let num = GrouCoeff::new(
+                    self.num.is_negative() ^ other.num.is_negative(),
+                    self.num.magnitude() * &other.den,
+                );
+                GrouRatio::new(num, &self.den * other.num.magnitude())
+            }
+        }
+    };
+}
+
+impl_ops_ratio!(GrouRatio, GrouRatio);
+impl_ops_ratio!(GrouRatio, &GrouRatio);
+impl_ops_ratio!(&GrouRatio, GrouRatio);
+impl_ops_ratio!(&GrouRatio, &GrouRatio);
@@ -0,0 +1,147 @@
+use super::grou::Grou;
+use super::sieve::sieve_primes;
+
+impl Grou {
+    /// Returns floor(sqrt(self)).
+    ///
+    /// Uses Newton's iteration, seeded with the floating point square root
+    /// of the top 64 bits. The seed is rounded up so that the iterates
+    /// decrease monotonically to the root.
+    pub fn isqrt(&self) -> Grou {
+        if self.is_zero() {
+            return Grou::from(0);
+        }
+
+        // Even shift, so that sqrt(2^shift) is exact.
+        let bits = self.bit_length();
+        let shift = (bits.saturating_sub(64) + 1) & !1;
+        let top = self.shift_right(shift);
+        let top = top.data.first().copied().unwrap_or(0);
+        let seed = (top as f64).sqrt() as u64 + 2;
+        let mut x = Grou::from(seed).shift_left(shift / 2);
+
+        loop {
+            let y = (&x + self / &x).shift_right(1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Returns (s, r) where s = floor(sqrt(self)) and r = self - s^2.
+    pub fn sqrt_rem(&self) -> (Grou, Grou) {
+        let root = self.isqrt();
+        let remainder = self - &root * &root;
+        (root, remainder)
+    }
+
+    /// Returns whether self is the square of an integer.
+    pub fn is_perfect_square(&self) -> bool {
+        if self.is_zero() {
+            return true;
+        }
+
+        // Squares can only take 44 values modulo 256, which we can read
+        // straight off the lowest block.
+        let low = self.data[0] as u8;
+        if !SQUARES_MOD_256[low as usize] {
+            return false;
+        }
+
+        // One pass over the number filters out most of the rest, using
+        // 45045 = 63 * 65 * 11.
+        let residue = self.rem_small(45045);
+        if !is_square_mod(residue % 63, 63)
+            || !is_square_mod(residue % 65, 65)
+            || !is_square_mod(residue % 11, 11)
+        {
+            return false;
+        }
+
+        let (_, remainder) = self.sqrt_rem();
+        remainder.is_zero()
+    }
+}
+
+const SQUARES_MOD_256: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[(i * i) % 256] = true;
+        i += 1;
+    }
+    table
+};
+
+#[inline]
+fn is_square_mod(residue: u64, modulus: u64) -> bool {
+    (0..modulus).any(|i| (i * i) % modulus == residue)
+}
+
+impl Grou {
+    /// Returns floor(self^(1/n)). Panics if n is 0.
+    ///
+    /// Uses Newton's iteration x -> ((n - 1) * x + self / x^(n - 1)) / n,
+    /// starting from 2^ceil(bits / n), which is never below the root.
+    pub fn nth_root(&self, n: u32) -> Grou {
+        match n {
+            0 => panic!("0th root is undefined"),
+            1 => {
+                let mut result = self.clone();
+                result.trim();
+                return result;
+            }
+            2 => return self.isqrt(),
+            _ => (),
+        }
+        if self.is_zero() {
+            return Grou::from(0);
+        }
+
+        let bits = self.bit_length();
+        let n_size = n as usize;
+        if bits <= n_size {
+            // 1 <= self < 2^n, so the root is 1.
+            return Grou::from(1);
+        }
+        let mut x = Grou::from(1).shift_left(bits.div_ceil(n_size));
+
+        loop {
+            let mut y = self / x.pow(n - 1);
+            y += x.clone() * (n as u64 - 1);
+            let y = y / (n as u64);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// If self = b^k for some k >= 2, returns (b, k) with the largest such k.
+    /// Returns None otherwise, and for 0 and 1, which are powers of
+    /// themselves for every k.
+    pub fn perfect_power(&self) -> Option<(Grou, u32)> {
+        if self <= &Grou::from(1) {
+            return None;
+        }
+
+        // If self = b^k, then self is also a p-th power for each prime p
+        // dividing k, and b^k <= self means k is at most the bit length.
+        let bits = self.bit_length();
+        for p in sieve_primes(bits) {
+            let p = p as u32;
+            if p == 2 && !self.is_perfect_square() {
+                continue;
+            }
+            let root = self.nth_root(p);
+            if &root.pow(p) == self {
+                return match root.perfect_power() {
+                    Some((base, k)) => Some((base, k * p)),
+                    None => Some((root, p)),
+                };
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,55 @@
+use super::grou::Grou;
+
+/// How to round a result that falls between two representable values.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// To the nearest value, with ties going to the even one.
+    HalfEven,
+    /// To the nearest value, with ties going away from 0.
+    HalfUp,
+    /// Toward 0, which discards the dropped digits.
+    TowardZero,
+    /// Away from 0.
+    AwayFromZero,
+    /// Toward negative infinity.
+    Floor,
+    /// Toward positive infinity.
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// Returns the magnitude of a rounded quotient, given the truncated
+    /// quotient of the magnitudes, the remainder and the divisor.
+    /// negative is the sign of the exact quotient.
+    pub(crate) fn round_quotient(
+        self,
+        quotient: Grou,
+        remainder: &Grou,
+        divisor: &Grou,
+        negative: bool,
+    ) -> Grou {
+        if remainder.is_zero() {
+            return quotient;
+        }
+        let round_up = match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::HalfEven | RoundingMode::HalfUp => {
+                match remainder.shift_left(1).partial_cmp(divisor).unwrap() {
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Greater => true,
+                    std::cmp::Ordering::Equal => {
+                        self == RoundingMode::HalfUp || !quotient.is_even()
+                    }
+                }
+            }
+        };
+        if round_up {
+            quotient + Grou::from(1)
+        } else {
+            quotient
+        }
+    }
+}
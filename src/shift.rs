@@ -0,0 +1,113 @@
+use super::grou::Grou;
+
+impl Grou {
+    /// Returns the number of significant bits, ignoring any high 0 blocks.
+    /// 0 has a bit length of 0.
+    pub fn bit_length(&self) -> usize {
+        for (ind, val) in self.data.iter().enumerate().rev() {
+            if *val != 0 {
+                return ind * 64 + (64 - val.leading_zeros() as usize);
+            }
+        }
+        0
+    }
+
+    /// Returns the number of trailing 0 bits. 0 has no set bits, so this
+    /// returns 0 for it.
+    pub fn trailing_zeros(&self) -> usize {
+        for (ind, val) in self.data.iter().enumerate() {
+            if *val != 0 {
+                return ind * 64 + val.trailing_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    /// Returns whether the bit at the given position is set.
+    pub fn bit(&self, position: usize) -> bool {
+        match self.data.get(position / 64) {
+            Some(val) => (val >> (position % 64)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Multiplies self by 2^bits.
+    pub fn shift_left(&self, bits: usize) -> Grou {
+        let block_shift = bits / 64;
+        let bit_shift = bits % 64;
+        let mut result = Vec::<u64>::with_capacity(self.len() + block_shift + 1);
+        result.resize(block_shift, 0);
+
+        if bit_shift == 0 {
+            result.extend_from_slice(&self.data[..]);
+        } else {
+            let mut carry = 0u64;
+            for val in self.data.iter() {
+                result.push((val << bit_shift) | carry);
+                carry = val >> (64 - bit_shift);
+            }
+            result.push(carry);
+        }
+
+        let mut g = Grou::from(result);
+        g.trim();
+        g
+    }
+
+    /// Divides self by 2^bits, discarding the remainder.
+    pub fn shift_right(&self, bits: usize) -> Grou {
+        let block_shift = bits / 64;
+        let bit_shift = bits % 64;
+        if block_shift >= self.len() {
+            return Grou::from(0);
+        }
+
+        let source = &self.data[block_shift..];
+        let mut result = Vec::<u64>::with_capacity(source.len());
+        if bit_shift == 0 {
+            result.extend_from_slice(source);
+        } else {
+            for (ind, val) in source.iter().enumerate() {
+                let high = source.get(ind + 1).map_or(0, |next| next << (64 - bit_shift));
+                result.push((val >> bit_shift) | high);
+            }
+        }
+
+        let mut g = Grou::from(result);
+        g.trim();
+        g
+    }
+}
+
+macro_rules! impl_shift {
+    ($type1: ty) => {
+        impl std::ops::Shl<usize> for $type1 {
+            type Output = Grou;
+            fn shl(self, bits: usize) -> Grou {
+                self.shift_left(bits)
+            }
+        }
+
+        impl std::ops::Shr<usize> for $type1 {
+            type Output = Grou;
+            fn shr(self, bits: usize) -> Grou {
+                self.shift_right(bits)
+            }
+        }
+    };
+}
+
+impl_shift!(Grou);
+impl_shift!(&Grou);
+
+impl std::ops::ShlAssign<usize> for Grou {
+    fn shl_assign(&mut self, bits: usize) {
+        *self = self.shift_left(bits);
+    }
+}
+
+impl std::ops::ShrAssign<usize> for Grou {
+    fn shr_assign(&mut self, bits: usize) {
+        *self = self.shift_right(bits);
+    }
+}
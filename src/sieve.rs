@@ -0,0 +1,44 @@
+/// Returns all primes <= limit, using the sieve of Eratosthenes.
+pub(crate) fn sieve_primes(limit: usize) -> Vec<u64> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if is_composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        let mut multiple = i * i;
+        while multiple <= limit {
+            is_composite[multiple] = true;
+            multiple += i;
+        }
+    }
+    primes
+}
+
+/// The 168 primes below 1000, used for trial division.
+pub(crate) const SMALL_PRIMES: [u64; 168] = {
+    let mut primes = [0u64; 168];
+    let mut count = 0;
+    let mut candidate = 2u64;
+    while count < 168 {
+        let mut divisor = 2u64;
+        let mut is_prime = true;
+        while divisor * divisor <= candidate {
+            if candidate.is_multiple_of(divisor) {
+                is_prime = false;
+                break;
+            }
+            divisor += 1;
+        }
+        if is_prime {
+            primes[count] = candidate;
+            count += 1;
+        }
+        candidate += 1;
+    }
+    primes
+};
@@ -0,0 +1,186 @@
+use super::grou::Grou;
+
+/// A signed big integer, stored as a sign and a Grou magnitude.
+/// This is used where results may be negative, such as the Bézout
+/// coefficients of the extended gcd. 0 is never negative.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GrouCoeff {
+    negative: bool,
+    magnitude: Grou,
+}
+
+impl GrouCoeff {
+    pub fn new(negative: bool, magnitude: Grou) -> GrouCoeff {
+        let mut magnitude = magnitude;
+        magnitude.trim();
+        if magnitude.is_empty() {
+            magnitude = Grou::from(0);
+        }
+        let negative = negative && !magnitude.is_zero();
+        GrouCoeff {
+            negative,
+            magnitude,
+        }
+    }
+
+    ///Returns whether the number is strictly below 0.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    ///Returns the absolute value.
+    #[inline]
+    pub fn magnitude(&self) -> &Grou {
+        &self.magnitude
+    }
+
+    pub fn into_parts(self) -> (bool, Grou) {
+        (self.negative, self.magnitude)
+    }
+
+    /// Returns the representative of self in [0, modulus).
+    pub fn rem_euclid(&self, modulus: &Grou) -> Grou {
+        let r = &self.magnitude % modulus;
+        if self.negative && !r.is_zero() {
+            modulus - r
+        } else {
+            r
+        }
+    }
+
+    fn add_signed(&self, other_negative: bool, other: &Grou) -> GrouCoeff {
+        if self.negative == other_negative {
+            return GrouCoeff::new(self.negative, &self.magnitude + other);
+        }
+        let (self_larger, difference) = self.magnitude.sub_with_sign(other);
+        if self_larger {
+            GrouCoeff::new(self.negative, difference)
+        } else {
+            GrouCoeff::new(other_negative, difference)
+        }
+    }
+}
+
+impl From<Grou> for GrouCoeff {
+    fn from(magnitude: Grou) -> GrouCoeff {
+        GrouCoeff::new(false, magnitude)
+    }
+}
+
+impl From<i64> for GrouCoeff {
+    fn from(small_num: i64) -> GrouCoeff {
+        GrouCoeff::new(small_num < 0, Grou::from(small_num.unsigned_abs()))
+    }
+}
+
+impl std::ops::Neg for GrouCoeff {
+    type Output = GrouCoeff;
+    fn neg(self) -> GrouCoeff {
+        GrouCoeff::new(!self.negative, self.magnitude)
+    }
+}
+
+impl std::ops::Neg for &GrouCoeff {
+    type Output = GrouCoeff;
+    fn neg(self) -> GrouCoeff {
+        GrouCoeff::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl std::cmp::PartialOrd for GrouCoeff {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.negative, other.negative) {
+            (false, true) => Some(std::cmp::Ordering::Greater),
+            (true, false) => Some(std::cmp::Ordering::Less),
+            (false, false) => self.magnitude.partial_cmp(&other.magnitude),
+            (true, true) => other.magnitude.partial_cmp(&self.magnitude),
+        }
+    }
+}
+
+macro_rules! impl_ops_coeff {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::ops::Add<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn add(self, other: $rhs) -> GrouCoeff {
+                self.add_signed(other.negative, &other.magnitude)
+            }
+        }
+
+        impl std::ops::Sub<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn sub(self, other: $rhs) -> GrouCoeff {
+                self.add_signed(!other.negative, &other.magnitude)
+            }
+        }
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn mul(self, other: $rhs) -> GrouCoeff {
+                GrouCoeff::new(
+                    self.negative ^ other.negative,
+                    &self.magnitude * &other.magnitude,
+                )
+            }
+        }
+    };
+}
+
+impl_ops_coeff!(GrouCoeff, GrouCoeff);
+impl_ops_coeff!(GrouCoeff, &GrouCoeff);
+impl_ops_coeff!(&GrouCoeff, GrouCoeff);
+impl_ops_coeff!(&GrouCoeff, &GrouCoeff);
+
+macro_rules! impl_ops_coeff_grou {
+    ($lhs: ty, $rhs: ty) => {
+        impl std::ops::Add<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn add(self, other: $rhs) -> GrouCoeff {
+                self.add_signed(false, &other)
+            }
+        }
+
+        impl std::ops::Sub<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn sub(self, other: $rhs) -> GrouCoeff {
+                self.add_signed(true, &other)
+            }
+        }
+
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = GrouCoeff;
+            fn mul(self, other: $rhs) -> GrouCoeff {
+                GrouCoeff::new(self.negative, &self.magnitude * &other)
+            }
+        }
+    };
+}
+
+impl_ops_coeff_grou!(GrouCoeff, Grou);
+impl_ops_coeff_grou!(GrouCoeff, &Grou);
+impl_ops_coeff_grou!(&GrouCoeff, Grou);
+impl_ops_coeff_grou!(&GrouCoeff, &Grou);
+
+impl std::fmt::Display for GrouCoeff {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let digits = crate::radix_convert::convert_to_decimal_string(&self.magnitude);
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+/// Parses an optional leading '-' followed by a number in any of the
+/// formats accepted by Grou.
+impl std::convert::From<&str> for GrouCoeff {
+    fn from(s: &str) -> GrouCoeff {
+        match s.strip_prefix('-') {
+            Some(magnitude) => GrouCoeff::new(true, Grou::from(magnitude)),
+            None => GrouCoeff::new(false, Grou::from(s)),
+        }
+    }
+}
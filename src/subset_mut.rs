@@ -0,0 +1,133 @@
+use super::grou::{Grou, GrouSubset};
+use super::limbs;
+
+/// A mutable view of the blocks start..end of a Grou.
+///
+/// The view keeps hold of the rest of its parent above end, so carries and
+/// borrows from in-place operations run on into the parent, as they would
+/// if the whole number had been updated. Only what runs off the top of the
+/// parent is returned.
+#[derive(Debug)]
+pub struct GrouSubsetMut<'a> {
+    // From the start of the view to the end of the parent.
+    data: &'a mut [u64],
+    len: usize,
+}
+
+impl<'a> GrouSubsetMut<'a> {
+    // A view of the first len blocks of data, which carries into the rest.
+    pub(crate) fn new(data: &'a mut [u64], len: usize) -> GrouSubsetMut<'a> {
+        assert!(len <= data.len(), "Subset is out of bounds");
+        GrouSubsetMut { data, len }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The blocks of the view, without the rest of the parent.
+    #[inline]
+    pub fn blocks(&self) -> &[u64] {
+        &self.data[..self.len]
+    }
+
+    #[inline]
+    pub fn blocks_mut(&mut self) -> &mut [u64] {
+        &mut self.data[..self.len]
+    }
+
+    pub fn as_subset(&self) -> GrouSubset<'_> {
+        GrouSubset {
+            data: self.blocks(),
+        }
+    }
+
+    /// Adds other to the view, with the carry running into the parent.
+    /// Returns true if it ran off the top of the parent. other must not be
+    /// longer than the view.
+    pub fn add_assign(&mut self, other: GrouSubset<'_>) -> bool {
+        self.add_shifted(other, 0)
+    }
+
+    /// Adds other * 2^(64 * shift) to the view, with the carry running into
+    /// the parent. Returns true if it ran off the top of the parent.
+    /// other, shifted, must fit in the view.
+    pub fn add_shifted(&mut self, other: GrouSubset<'_>, shift: usize) -> bool {
+        assert!(
+            shift + other.len() <= self.len,
+            "Operand does not fit in the subset"
+        );
+        let (low, high) = self.data[shift..].split_at_mut(other.len());
+        let carry = limbs::add_n_assign(low, other.data);
+        limbs::add_1_assign(high, carry) != 0
+    }
+
+    /// Subtracts other from the view, with the borrow running into the
+    /// parent. Returns true if it ran off the top of the parent, in which
+    /// case the parent has wrapped around. other must not be longer than
+    /// the view.
+    pub fn sub_assign(&mut self, other: GrouSubset<'_>) -> bool {
+        assert!(
+            other.len() <= self.len,
+            "Operand does not fit in the subset"
+        );
+        let (low, high) = self.data.split_at_mut(other.len());
+        let borrow = limbs::sub_n_assign(low, other.data);
+        limbs::sub_1_assign(high, borrow) != 0
+    }
+
+    /// Splits the view at mid. The high part keeps carrying into the
+    /// parent, while the low part only reaches up to mid, since the high
+    /// part is borrowed separately; its carries are returned instead.
+    pub fn split_at_mut(self, mid: usize) -> (GrouSubsetMut<'a>, GrouSubsetMut<'a>) {
+        assert!(mid <= self.len, "Split point is out of bounds");
+        let len = self.len;
+        let (low, high) = self.data.split_at_mut(mid);
+        (
+            GrouSubsetMut::new(low, mid),
+            GrouSubsetMut::new(high, len - mid),
+        )
+    }
+}
+
+impl Grou {
+    /// Returns a mutable view of the blocks start..end, which carries into
+    /// the blocks above end.
+    pub fn subset_mut(&mut self, start: usize, end: usize) -> GrouSubsetMut<'_> {
+        assert!(start <= end && end <= self.len(), "Subset is out of bounds");
+        GrouSubsetMut::new(&mut self.data[start..], end - start)
+    }
+
+    /// Splits the blocks at mid into two mutable views. The high view
+    /// covers the rest of the number, and the low view returns its carries
+    /// instead of passing them to the high one.
+    pub fn split_at_mut(&mut self, mid: usize) -> (GrouSubsetMut<'_>, GrouSubsetMut<'_>) {
+        let len = self.len();
+        self.subset_mut(0, len).split_at_mut(mid)
+    }
+
+    /// Iterates over mutable views of consecutive blocks of the given
+    /// length, the last one possibly shorter. Each view returns its carries
+    /// instead of passing them to the next one. A block_length of 0 gives
+    /// no views, as with BlockIterator.
+    pub fn blocks_mut(
+        &mut self,
+        block_length: usize,
+    ) -> impl DoubleEndedIterator<Item = GrouSubsetMut<'_>> + ExactSizeIterator {
+        let data: &mut [u64] = if block_length == 0 {
+            &mut []
+        } else {
+            &mut self.data
+        };
+        data.chunks_mut(block_length.max(1)).map(|chunk| {
+            let len = chunk.len();
+            GrouSubsetMut::new(chunk, len)
+        })
+    }
+}
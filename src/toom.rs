@@ -0,0 +1,398 @@
+use super::block_iterator::{calculate_block_length, BlockIterator};
+use super::grou::{AsLimbs, Grou};
+use super::limbs;
+use super::mul::PARALLEL_THRESHOLD;
+use super::ops::{mul_limbs, significant, sub_limbs, sub_with_sign_limbs};
+use super::ratio::GrouRatio;
+use std::sync::OnceLock;
+
+// Toom-Cook multiplication. Each operand is cut into k parts of n blocks,
+// which are read as the coefficients of a polynomial of degree k - 1 in
+// X = 2^(64 n). The polynomials are evaluated at 2k - 1 points, the values
+// are multiplied pairwise, and the 2k - 1 coefficients of the product
+// polynomial are recovered by interpolation, then added up at their offsets.
+//
+// A ToomScheme only holds data: the evaluation weights for each point and
+// the interpolation matrix, which is the inverse of the Vandermonde matrix
+// of the points. Toom-3, Toom-4 and Toom-8 are instances of it.
+
+/// Balanced operands at least this long use Toom-4.
+pub const TOOM_4_THRESHOLD: usize = 1500;
+
+/// Balanced operands at least this long use Toom-8.
+pub const TOOM_8_THRESHOLD: usize = 2400;
+
+/// A Toom-k multiplication scheme, given by its evaluation points.
+///
+/// A point p/q is given as the pair (p, q), and (1, 0) stands for infinity.
+/// There must be 2k - 1 distinct points. Points with small p and q keep the
+/// evaluated values short; the weights p^i q^(2k - 2 - i) must fit in an
+/// i64, as must the entries of the interpolation matrix once each row is
+/// brought to a common denominator.
+#[derive(Clone, Debug)]
+pub struct ToomScheme {
+    parts: usize,
+    points: Vec<(i64, i64)>,
+    // For each point, the weight p^i q^(parts - 1 - i) of each part.
+    evaluation: Vec<Vec<i64>>,
+    // For each coefficient of the product, a row of the inverse Vandermonde
+    // matrix, as numerators over a common denominator.
+    interpolation: Vec<(Vec<i64>, u64)>,
+}
+
+impl ToomScheme {
+    /// Builds the scheme that cuts operands into parts parts and evaluates
+    /// at the given points. Panics if there are not 2 * parts - 1 distinct
+    /// points, or if the weights or the interpolation matrix overflow.
+    pub fn new(parts: usize, points: &[(i64, i64)]) -> ToomScheme {
+        assert!(parts >= 2, "Toom-Cook needs at least 2 parts");
+        assert_eq!(
+            points.len(),
+            2 * parts - 1,
+            "Toom-Cook needs 2 * parts - 1 points"
+        );
+
+        let weights = |p: i64, q: i64, degree: usize| -> Vec<i64> {
+            (0..=degree)
+                .map(|i| {
+                    p.checked_pow(i as u32)
+                        .and_then(|x| x.checked_mul(q.checked_pow((degree - i) as u32)?))
+                        .expect("Evaluation point is too large")
+                })
+                .collect()
+        };
+        let evaluation = points
+            .iter()
+            .map(|(p, q)| weights(*p, *q, parts - 1))
+            .collect();
+        let vandermonde: Vec<Vec<i64>> = points
+            .iter()
+            .map(|(p, q)| weights(*p, *q, points.len() - 1))
+            .collect();
+
+        let interpolation = invert(&vandermonde)
+            .into_iter()
+            .map(|row| common_denominator(&row))
+            .collect();
+
+        ToomScheme {
+            parts,
+            points: points.to_vec(),
+            evaluation,
+            interpolation,
+        }
+    }
+
+    /// Toom-3, evaluated at 0, 1, -1, 2 and infinity.
+    pub fn toom_3() -> &'static ToomScheme {
+        static SCHEME: OnceLock<ToomScheme> = OnceLock::new();
+        SCHEME.get_or_init(|| ToomScheme::new(3, &[(0, 1), (1, 1), (-1, 1), (2, 1), (1, 0)]))
+    }
+
+    /// Toom-4, evaluated at 0, ±1, ±2, 1/2 and infinity.
+    pub fn toom_4() -> &'static ToomScheme {
+        static SCHEME: OnceLock<ToomScheme> = OnceLock::new();
+        SCHEME.get_or_init(|| {
+            ToomScheme::new(
+                4,
+                &[(0, 1), (1, 1), (-1, 1), (2, 1), (-2, 1), (1, 2), (1, 0)],
+            )
+        })
+    }
+
+    /// Toom-8, evaluated at 0, ±1, ±2, ±1/2, ±3, ±1/3, ±4, 1/4 and
+    /// infinity.
+    pub fn toom_8() -> &'static ToomScheme {
+        static SCHEME: OnceLock<ToomScheme> = OnceLock::new();
+        SCHEME.get_or_init(|| {
+            ToomScheme::new(
+                8,
+                &[
+                    (0, 1),
+                    (1, 1),
+                    (-1, 1),
+                    (2, 1),
+                    (-2, 1),
+                    (1, 2),
+                    (-1, 2),
+                    (3, 1),
+                    (-3, 1),
+                    (1, 3),
+                    (-1, 3),
+                    (4, 1),
+                    (-4, 1),
+                    (1, 4),
+                    (1, 0),
+                ],
+            )
+        })
+    }
+
+    /// The number of parts each operand is cut into.
+    pub fn parts(&self) -> usize {
+        self.parts
+    }
+
+    pub fn points(&self) -> &[(i64, i64)] {
+        &self.points
+    }
+
+    /// Multiplies a and b with one level of this scheme. The products at
+    /// the points go through the usual multiplication, which may use Toom
+    /// again.
+    pub fn mul<A, B>(&self, a: &A, b: &B) -> Grou
+    where
+        A: AsLimbs + ?Sized,
+        B: AsLimbs + ?Sized,
+    {
+        let a = significant(a.limbs());
+        let b = significant(b.limbs());
+        if a.is_empty() || b.is_empty() {
+            return Grou::from(0);
+        }
+
+        let block_length = calculate_block_length(self.parts, a, b);
+        let a_parts: Vec<&[u64]> = BlockIterator::new(a, block_length)
+            .map(|part| part.data)
+            .collect();
+        let b_parts: Vec<&[u64]> = BlockIterator::new(b, block_length)
+            .map(|part| part.data)
+            .collect();
+
+        let evaluated: Vec<(bool, Grou, Grou)> = self
+            .evaluation
+            .iter()
+            .map(|weights| {
+                let (a_negative, a_value) = evaluate(&a_parts, weights, block_length);
+                let (b_negative, b_value) = evaluate(&b_parts, weights, block_length);
+                (a_negative ^ b_negative, a_value, b_value)
+            })
+            .collect();
+        let product = |(negative, a_value, b_value): &(bool, Grou, Grou)| {
+            (*negative, mul_limbs(&a_value.data, &b_value.data))
+        };
+        let values: Vec<(bool, Grou)> = if b.len().min(a.len()) >= PARALLEL_THRESHOLD {
+            crate::parallel::map(&evaluated, &product)
+        } else {
+            evaluated.iter().map(product).collect()
+        };
+
+        let mut result = vec![0u64; a.len() + b.len()];
+        for (ind, (row, denominator)) in self.interpolation.iter().enumerate() {
+            let coefficient = interpolate(&values, row, *denominator);
+            let coefficient = significant(&coefficient);
+            if coefficient.is_empty() {
+                continue;
+            }
+            // The coefficient is not negative, so it fits in the product at
+            // its offset.
+            let (low, high) = result[ind * block_length..].split_at_mut(coefficient.len());
+            let carry = limbs::add_n_assign(low, coefficient);
+            let carry = limbs::add_1_assign(high, carry);
+            debug_assert_eq!(carry, 0);
+        }
+
+        let mut result = Grou::from(result);
+        result.trim();
+        result
+    }
+}
+
+// Returns the sign and the magnitude of sum(weights[i] * parts[i]).
+// Each part is at most block_length long, and the weights are below 2^63,
+// so the sums of the positive and of the negative terms take at most two
+// more blocks.
+fn evaluate(parts: &[&[u64]], weights: &[i64], block_length: usize) -> (bool, Grou) {
+    let mut positive = vec![0u64; block_length + 2];
+    let mut negative = vec![0u64; block_length + 2];
+    for (part, weight) in parts.iter().zip(weights.iter()) {
+        let sum = if *weight < 0 {
+            &mut negative
+        } else {
+            &mut positive
+        };
+        addmul(sum, part, weight.unsigned_abs());
+    }
+    let (positive_larger, magnitude) = sub_with_sign_limbs(&positive, &negative);
+    (!positive_larger, magnitude)
+}
+
+// Returns (sum(row[i] * values[i])) / denominator, which is exact and not
+// negative, since it is a coefficient of the product.
+fn interpolate(values: &[(bool, Grou)], row: &[i64], denominator: u64) -> Vec<u64> {
+    let length = values
+        .iter()
+        .map(|(_, value)| value.len())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let mut positive = vec![0u64; length];
+    let mut negative = vec![0u64; length];
+    for ((value_negative, value), factor) in values.iter().zip(row.iter()) {
+        let sum = if value_negative ^ (*factor < 0) {
+            &mut negative
+        } else {
+            &mut positive
+        };
+        addmul(sum, &value.data, factor.unsigned_abs());
+    }
+
+    let mut coefficient = sub_limbs(&positive, &negative).data.to_vec();
+    divexact_1(&mut coefficient, denominator);
+    coefficient
+}
+
+// sum += a * b, where sum is long enough to hold the result.
+fn addmul(sum: &mut [u64], a: &[u64], b: u64) {
+    if b == 0 {
+        return;
+    }
+    let (low, high) = sum.split_at_mut(a.len());
+    let carry = limbs::addmul_1(low, a, b);
+    let carry = limbs::add_1_assign(high, carry);
+    debug_assert_eq!(carry, 0);
+}
+
+// Divides rp by d in place, when d is known to divide it. The odd part of
+// d is divided out by multiplying with its inverse modulo 2^64, which
+// needs no division instructions, then the power of 2 is shifted out.
+fn divexact_1(rp: &mut [u64], d: u64) {
+    let shift = d.trailing_zeros();
+    let odd = d >> shift;
+    if odd != 1 {
+        // Newton's iteration doubles the number of correct low bits, and
+        // odd is its own inverse modulo 2^3.
+        let mut inverse = odd;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(odd.wrapping_mul(inverse)));
+        }
+
+        let mut borrow = 0u64;
+        for r in rp.iter_mut() {
+            let (value, overflow) = r.overflowing_sub(borrow);
+            let quotient = value.wrapping_mul(inverse);
+            *r = quotient;
+            borrow = ((quotient as u128 * odd as u128) >> 64) as u64 + overflow as u64;
+        }
+        debug_assert_eq!(borrow, 0, "Division is not exact");
+    }
+    if shift > 0 {
+        debug_assert!(rp.first().is_none_or(|val| val.trailing_zeros() >= shift));
+        let mut carry = 0u64;
+        for r in rp.iter_mut().rev() {
+            let value = *r;
+            *r = (value >> shift) | carry;
+            carry = value << (64 - shift);
+        }
+    }
+}
+
+// Inverts a square matrix of integers exactly. Panics if it is singular,
+// which means that two points were the same.
+fn invert(matrix: &[Vec<i64>]) -> Vec<Vec<GrouRatio>> {
+    let size = matrix.len();
+    let mut rows: Vec<Vec<GrouRatio>> = matrix
+        .iter()
+        .enumerate()
+        .map(|(ind, row)| {
+            let mut augmented: Vec<GrouRatio> =
+                row.iter().map(|val| GrouRatio::from(*val)).collect();
+            augmented.extend((0..size).map(|col| GrouRatio::from((col == ind) as i64)));
+            augmented
+        })
+        .collect();
+
+    for col in 0..size {
+        let pivot = (col..size)
+            .find(|row| !rows[*row][col].numer().is_zero())
+            .expect("Evaluation points are not distinct");
+        rows.swap(col, pivot);
+        let pivot_value = rows[col][col].clone();
+        for val in rows[col].iter_mut() {
+            *val = &*val / &pivot_value;
+        }
+        for row in 0..size {
+            if row == col || rows[row][col].numer().is_zero() {
+                continue;
+            }
+            let factor = rows[row][col].clone();
+            let pivot_row = rows[col].clone();
+            for (val, pivot_val) in rows[row].iter_mut().zip(pivot_row.iter()) {
+                *val = &*val - &(&factor * pivot_val);
+            }
+        }
+    }
+
+    rows.into_iter()
+        .map(|mut row| row.split_off(size))
+        .collect()
+}
+
+// Brings a row of fractions to a common denominator, as integers.
+fn common_denominator(row: &[GrouRatio]) -> (Vec<i64>, u64) {
+    let mut denominator = Grou::from(1);
+    for val in row.iter() {
+        let gcd = denominator.gcd(val.denom());
+        denominator = &(&denominator / &gcd) * val.denom();
+    }
+    let numerators = row
+        .iter()
+        .map(|val| {
+            let numerator = val.numer() * &(&denominator / val.denom());
+            let (negative, magnitude) = numerator.into_parts();
+            let magnitude = i64::try_from(to_small(&magnitude))
+                .expect("Interpolation matrix entry is too large");
+            if negative {
+                -magnitude
+            } else {
+                magnitude
+            }
+        })
+        .collect();
+    (numerators, to_small(&denominator))
+}
+
+fn to_small(g: &Grou) -> u64 {
+    let data = significant(&g.data);
+    assert!(data.len() <= 1, "Interpolation matrix entry is too large");
+    data.first().copied().unwrap_or(0)
+}
+
+impl Grou {
+    /// Multiplies with one level of Toom-3, cutting the operands into 3
+    /// parts.
+    pub fn toom_3_mul<R: AsLimbs + ?Sized>(&self, rhs: &R) -> Grou {
+        ToomScheme::toom_3().mul(self, rhs)
+    }
+
+    /// Multiplies with one level of Toom-4, cutting the operands into 4
+    /// parts.
+    pub fn toom_4_mul<R: AsLimbs + ?Sized>(&self, rhs: &R) -> Grou {
+        ToomScheme::toom_4().mul(self, rhs)
+    }
+
+    /// Multiplies with one level of Toom-8, cutting the operands into 8
+    /// parts.
+    pub fn toom_8_mul<R: AsLimbs + ?Sized>(&self, rhs: &R) -> Grou {
+        ToomScheme::toom_8().mul(self, rhs)
+    }
+}
+
+// Picks the Toom scheme for operands of these lengths, if any. Unbalanced
+// operands are left to mul_into, which cuts the longer one into chunks.
+pub(crate) fn select(a_len: usize, b_len: usize) -> Option<&'static ToomScheme> {
+    let (long, short) = if a_len >= b_len {
+        (a_len, b_len)
+    } else {
+        (b_len, a_len)
+    };
+    if 2 * short <= long {
+        None
+    } else if short >= TOOM_8_THRESHOLD {
+        Some(ToomScheme::toom_8())
+    } else if short >= TOOM_4_THRESHOLD {
+        Some(ToomScheme::toom_4())
+    } else {
+        None
+    }
+}
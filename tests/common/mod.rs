@@ -0,0 +1,51 @@
+// Helpers shared by the test files, which each include them with
+// `mod common;`. Not every file uses all of them.
+#![allow(dead_code)]
+
+use grou_num::grou::Grou;
+use grou_num::random::RngSource;
+
+// A small linear congruential generator, so the tests see the same numbers
+// on every run.
+pub struct Lcg(pub u64);
+
+impl RngSource for Lcg {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+}
+
+impl Lcg {
+    pub fn blocks(&mut self, length: usize) -> Vec<u64> {
+        (0..length).map(|_| self.next_u64()).collect()
+    }
+}
+
+// The product of a and b by schoolbook multiplication, as a reference for
+// the faster methods. It has a.len() + b.len() blocks.
+pub fn mul_schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = vec![0u64; a.len() + b.len()];
+    for (i, a_val) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b_val) in b.iter().enumerate() {
+            let t = (*a_val as u128) * (*b_val as u128) + out[i + j] as u128 + carry;
+            out[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        out[i + b.len()] = carry as u64;
+    }
+    out
+}
+
+// The Grou of blocks that may have high zero blocks, such as a product from
+// the block kernels.
+pub fn grou_trimmed(mut blocks: Vec<u64>) -> Grou {
+    while blocks.len() > 1 && blocks[blocks.len() - 1] == 0 {
+        blocks.pop();
+    }
+    Grou::from(blocks)
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod as_limbs_tests {
+    use grou_num::grou::{AsLimbs, Grou};
+
+    const M: u64 = u64::MAX;
+
+    // A block container outside the crate.
+    struct Blocks(Vec<u64>);
+
+    impl AsLimbs for Blocks {
+        fn limbs(&self) -> &[u64] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn test_mixed_operands() {
+        let g = Grou::from(vec![M, 2, 7]);
+        let low = g.subset(0, 2);
+        let high = g.subset(1, 3);
+
+        assert_eq!(&g + &low, Grou::from(vec![M - 1, 5, 7]));
+        assert_eq!(low.clone() + &g, Grou::from(vec![M - 1, 5, 7]));
+        assert_eq!(&g - &low, Grou::from(vec![0, 0, 7]));
+        assert_eq!(&g - high.clone(), Grou::from(vec![M - 2, M - 4, 6]));
+        assert_eq!(&high - &low, Grou::from(vec![3, 4]));
+        assert_eq!(
+            &low * &high,
+            &Grou::from(vec![M, 2]) * &Grou::from(vec![2, 7])
+        );
+        assert_eq!(&g * &[3u64][..], Grou::from(vec![M - 2, 8, 21]));
+        assert_eq!(&g / &high, Grou::from(vec![0, 1]));
+        assert_eq!(&g % &high, Grou::from(vec![M]));
+
+        let mut acc = Grou::from(1);
+        acc += &low;
+        acc += high;
+        acc -= &[1u64, 1][..];
+        assert_eq!(acc, Grou::from(vec![1, 9]));
+        acc *= &low;
+        assert_eq!(acc, &Grou::from(vec![1, 9]) * &Grou::from(vec![M, 2]));
+    }
+
+    #[test]
+    fn test_user_defined_container() {
+        let g = Grou::from(vec![5, 1]);
+        let b = Blocks(vec![7, 0, 0]);
+
+        assert_eq!(&g + &b, Grou::from(vec![12, 1, 0]));
+        assert_eq!(&g - &b, Grou::from(vec![M - 1]));
+        assert_eq!(g.subset_all() * &b, Grou::from(vec![35, 7]));
+        assert_eq!(
+            g.div_rem(&b),
+            (Grou::from(vec![0x2492492492492493]), Grou::from(0))
+        );
+        assert_eq!(g.sub_with_sign(&b), (true, Grou::from(vec![M - 1])));
+    }
+
+    #[test]
+    fn test_mutable_view_operand() {
+        let mut g = Grou::from(vec![3, 4]);
+        let h = Grou::from(vec![1, 1, 1]);
+        {
+            let view = g.subset_mut(0, 2);
+            assert_eq!(&h + &view, Grou::from(vec![4, 5, 1]));
+            assert_eq!(&h - view, Grou::from(vec![M - 1, M - 3]));
+        }
+        assert_eq!(g, Grou::from(vec![3, 4]));
+    }
+
+    #[test]
+    fn test_mutable_view_on_the_left() {
+        let mut g = Grou::from(vec![3, 4, 9]);
+        let h = Grou::from(vec![1, 1]);
+        let view = g.subset_mut(0, 2);
+        assert_eq!(&view + &h, Grou::from(vec![4, 5]));
+        assert_eq!(&view - h.subset_all(), Grou::from(vec![2, 3]));
+        assert_eq!(&view * &[2u64][..], Grou::from(vec![6, 8]));
+        assert_eq!(&view / &h, Grou::from(vec![3]));
+        assert_eq!(&view % &h, Grou::from(vec![0, 1]));
+        assert_eq!(view + h, Grou::from(vec![4, 5]));
+    }
+
+    #[test]
+    fn test_cross_type_comparisons() {
+        let mut g = Grou::from(vec![5, 0, 7]);
+        let small = Grou::from(vec![5]);
+        let big = Grou::from(vec![0, 1]);
+
+        let low = g.subset(0, 2);
+        assert!(small == low);
+        assert!(low == small);
+        assert!(small <= low);
+        assert!(big > low);
+        assert!(low < big);
+        assert!(g > low);
+
+        let view = g.subset_mut(0, 1);
+        assert!(view == small);
+        assert!(small == view);
+        assert!(view < big);
+        assert!(big > view);
+        assert!(view == small.subset_all());
+        assert!(big.subset_all() > view);
+    }
+
+    #[test]
+    #[should_panic(expected = "Subtraction leads to underflow")]
+    fn test_sub_assign_underflow() {
+        let mut g = Grou::from(vec![5]);
+        g -= &Grou::from(vec![0, 1]).subset_all();
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod block_iterator_tests {
+    use grou_num::block_iterator::{calculate_block_length, BlockIterator};
+    use grou_num::grou::{Grou, GrouSubset};
+
+    fn blocks<'a>(iter: impl Iterator<Item = GrouSubset<'a>>) -> Vec<Vec<u64>> {
+        iter.map(|block| block.data.to_vec()).collect()
+    }
+
+    #[test]
+    fn test_forward_and_backward() {
+        let g = &Grou::from(vec![1, 2, 3, 4, 5, 6, 7]);
+        let expected = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]];
+
+        assert_eq!(blocks(BlockIterator::new(g, 3)), expected);
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(blocks(BlockIterator::new(g, 3).rev()), reversed);
+
+        // Both ends meet in the middle.
+        let mut iter = BlockIterator::new(g, 2);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back().unwrap().data, &[7]);
+        assert_eq!(iter.next().unwrap().data, &[1, 2]);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back().unwrap().data, &[5, 6]);
+        assert_eq!(iter.next().unwrap().data, &[3, 4]);
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_empty_and_exact() {
+        let empty = Grou::from(vec![]);
+        assert_eq!(BlockIterator::new(&empty, 3).len(), 0);
+        assert_eq!(BlockIterator::new(&Grou::from(vec![1, 2]), 0).count(), 0);
+
+        let g = Grou::from(vec![1, 2, 3, 4]);
+        let iter = BlockIterator::new(&g, 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.block_length(), 2);
+        assert!(iter.map(|block| block.len()).all(|len| len == 2));
+
+        let short = Grou::from(vec![1]);
+        assert_eq!(calculate_block_length(3, &g, &short), 2);
+        assert_eq!(calculate_block_length(4, &short, g.subset_all().data), 1);
+    }
+
+    #[test]
+    fn test_split_n() {
+        let g = Grou::from(vec![1, 2, 3, 4, 5]);
+        let [a, b, c] = g.split_n::<3>();
+        assert_eq!(
+            (a.data, b.data, c.data),
+            (&[1, 2][..], &[3, 4][..], &[5][..])
+        );
+
+        // Parts past the end of the number are empty.
+        let [a, b, c, d] = g.split_n::<4>();
+        assert_eq!(
+            (a.data, b.data, c.data),
+            (&[1, 2][..], &[3, 4][..], &[5][..])
+        );
+        assert!(d.data.is_empty());
+
+        let empty = Grou::from(vec![]);
+        let [a, b] = empty.split_n::<2>();
+        assert!(a.data.is_empty() && b.data.is_empty());
+
+        let [whole] = g.split_n::<1>();
+        assert_eq!(whole.data, &[1, 2, 3, 4, 5]);
+        assert_eq!(g.make_chunks(2).len(), 2);
+
+        // No parts at all.
+        let none: [GrouSubset; 0] = g.split_n::<0>();
+        assert!(none.is_empty());
+        assert_eq!(g.make_chunks(0).count(), 0);
+        assert_eq!(calculate_block_length(0, &g, &g), 0);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod combinatorics_tests {
+    use grou_num::grou::Grou;
+
+    fn product_naive(factors: impl Iterator<Item = u64>) -> Grou {
+        let mut result = Grou::from(1);
+        for factor in factors {
+            result.multiply_small(factor);
+        }
+        result
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(Grou::factorial(0), Grou::from(1));
+        assert_eq!(Grou::factorial(20), Grou::from(2432902008176640000));
+        for n in [21u64, 100, 1000] {
+            assert_eq!(Grou::factorial(n), product_naive(1..=n), "{}!", n);
+        }
+    }
+
+    #[test]
+    fn test_double_factorial() {
+        assert_eq!(Grou::double_factorial(0), Grou::from(1));
+        assert_eq!(Grou::double_factorial(9), Grou::from(945));
+        assert_eq!(Grou::double_factorial(10), Grou::from(3840));
+        assert_eq!(
+            Grou::double_factorial(501),
+            product_naive((1..=501).step_by(2))
+        );
+    }
+
+    #[test]
+    fn test_primorial() {
+        assert_eq!(Grou::primorial(1), Grou::from(1));
+        assert_eq!(Grou::primorial(10), Grou::from(210));
+        assert_eq!(Grou::primorial(13), Grou::from(30030));
+        let primorial = Grou::primorial(1000);
+        assert_eq!(primorial.factor().len(), 168);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(Grou::binomial(5, 7), Grou::from(0));
+        assert_eq!(Grou::binomial(5, 0), Grou::from(1));
+        assert_eq!(Grou::binomial(5, 2), Grou::from(10));
+        assert_eq!(Grou::binomial(64, 32), Grou::from(1832624140942590534));
+
+        for (n, k) in [(100u64, 50u64), (1000, 3), (1000, 999), (777, 300)] {
+            let expected = Grou::factorial(n) / (Grou::factorial(k) * Grou::factorial(n - k));
+            assert_eq!(Grou::binomial(n, k), expected, "{} choose {}", n, k);
+        }
+    }
+
+    #[test]
+    fn test_binomial_large_n() {
+        // Small k must not depend on n, which is far too large to sieve.
+        assert_eq!(
+            Grou::binomial(300_000_000, 2),
+            Grou::from(44999999850000000)
+        );
+        let n = 10_000_000_000u64;
+        assert_eq!(Grou::binomial(n, 1), Grou::from(n));
+        assert_eq!(
+            Grou::binomial(n, n - 2),
+            Grou::from("49999999995000000000")
+        );
+        assert_eq!(
+            Grou::binomial(n, 3),
+            Grou::from("166666666616666666670000000000")
+        );
+    }
+}
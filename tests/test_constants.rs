@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod constants_tests {
+    use grou_num::constants::{e_digits, pi_digits, sqrt2_digits};
+
+    const PI_100: &str = "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+    const E_100: &str = "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274";
+    const SQRT2_100: &str = "1.4142135623730950488016887242096980785696718753769480731766797379907324784621070388503875343276415727";
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(pi_digits(100), PI_100);
+        assert_eq!(e_digits(100), E_100);
+        assert_eq!(sqrt2_digits(100), SQRT2_100);
+        for n in [0, 1, 5, 37] {
+            let prefix = if n == 0 { 1 } else { n + 2 };
+            assert_eq!(pi_digits(n), PI_100[..prefix]);
+            assert_eq!(e_digits(n), E_100[..prefix]);
+            assert_eq!(sqrt2_digits(n), SQRT2_100[..prefix]);
+        }
+    }
+
+    #[test]
+    fn test_truncation() {
+        // Digits 762 to 767 of pi are all 9; truncating inside them must
+        // not round up.
+        assert!(pi_digits(765).ends_with("349999"));
+        assert!(pi_digits(770).ends_with("51870721134999999837"));
+        assert!(e_digits(770).ends_with("87922849989208680582"));
+        assert!(sqrt2_digits(770).ends_with("50912277002269411275"));
+    }
+
+    #[test]
+    fn test_ten_thousand_digits() {
+        let pi = pi_digits(10000);
+        assert_eq!(pi.len(), 10002);
+        assert!(pi.ends_with("05600101655256375678"));
+        assert!(e_digits(10000).ends_with("87042300179465536788"));
+        assert!(sqrt2_digits(10000).ends_with("65553230285873258351"));
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod crt_tests {
+    use grou_num::crt::{crt, CrtBasis};
+    use grou_num::grou::Grou;
+
+    fn grous(values: &[u64]) -> Vec<Grou> {
+        values.iter().map(|v| Grou::from(*v)).collect()
+    }
+
+    #[test]
+    fn test_crt_small() {
+        let result = crt(&grous(&[2, 3, 2]), &grous(&[3, 5, 7]));
+        assert_eq!(result, Some(Grou::from(23)));
+
+        // Residues do not need to be reduced.
+        let result = crt(&grous(&[5, 8]), &grous(&[3, 5]));
+        assert_eq!(result, Some(Grou::from(8)));
+
+        assert_eq!(crt(&grous(&[1, 2]), &grous(&[4, 6])), None);
+        assert_eq!(crt(&grous(&[1, 2]), &grous(&[4, 0])), None);
+        assert_eq!(crt(&grous(&[1]), &grous(&[4, 5])), None);
+        assert_eq!(crt(&[], &[]), Some(Grou::from(0)));
+    }
+
+    #[test]
+    fn test_crt_basis_roundtrip() {
+        // The first 50 primes above 2^62.
+        let mut moduli = Vec::new();
+        let mut p = Grou::from(1 << 62);
+        for _ in 0..50 {
+            p = p.next_prime();
+            moduli.push(p.clone());
+        }
+        let basis = CrtBasis::new(&moduli).unwrap();
+        assert_eq!(basis.moduli(), &moduli[..]);
+        let product = moduli.iter().fold(Grou::from(1), |acc, m| acc * m);
+        assert_eq!(basis.modulus(), &product);
+
+        let x = Grou::factorial(500) % basis.modulus();
+        let residues = basis.reduce(&x);
+        for (r, m) in residues.iter().zip(moduli.iter()) {
+            assert_eq!(r, &(&x % m));
+        }
+        assert_eq!(basis.reconstruct(&residues), Some(x));
+        assert_eq!(basis.reconstruct(&residues[1..]), None);
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        let m1 = Grou::from(vec![u64::MAX, u64::MAX >> 1]);
+        let m2 = Grou::from((1 << 61) - 1);
+        let m3 = Grou::from(vec![0, 0, 1]);
+        let x = Grou::from(vec![7, 8, 9, 10, 11]);
+        let moduli = [m1, m2, m3];
+        let residues: Vec<Grou> = moduli.iter().map(|m| &x % m).collect();
+        let product = &moduli[0] * &moduli[1] * &moduli[2];
+        assert_eq!(crt(&residues, &moduli), Some(&x % &product));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod decimal_tests {
+    use grou_num::decimal::GrouDecimal;
+    use grou_num::rounding::RoundingMode;
+
+    fn dec(s: &str) -> GrouDecimal {
+        GrouDecimal::from(s)
+    }
+
+    #[test]
+    fn test_parse_display() {
+        for s in [
+            "0",
+            "-1",
+            "1.50",
+            "-0.05",
+            "0.000",
+            "123456789012345678901234567890.0123456789",
+        ] {
+            assert_eq!(dec(s).to_string(), s);
+        }
+        assert_eq!(dec("1.50").scale(), 2);
+        assert_eq!(dec("1.50"), dec("1.5"));
+        assert!(dec("-0.5") < dec("0.25"));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!((dec("1.25") + dec("0.7")).to_string(), "1.95");
+        assert_eq!((dec("1.25") - dec("3")).to_string(), "-1.75");
+        assert_eq!((dec("-1.5") * dec("0.25")).to_string(), "-0.375");
+        assert_eq!(-dec("2.5"), dec("-2.5"));
+
+        let third = dec("1").div_with_scale(&dec("3"), 100, RoundingMode::HalfEven);
+        let expected = format!("0.{}", "3".repeat(100));
+        assert_eq!(third.to_string(), expected);
+        let two = dec("0.02").div_with_scale(&dec("0.001"), 0, RoundingMode::HalfEven);
+        assert_eq!(two.to_string(), "20");
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let cases = [
+            // (value, HalfEven, HalfUp, TowardZero, AwayFromZero, Floor, Ceiling)
+            ("2.5", ["2", "3", "2", "3", "2", "3"]),
+            ("3.5", ["4", "4", "3", "4", "3", "4"]),
+            ("-2.5", ["-2", "-3", "-2", "-3", "-3", "-2"]),
+            ("2.4", ["2", "2", "2", "3", "2", "3"]),
+            ("-2.6", ["-3", "-3", "-2", "-3", "-3", "-2"]),
+            ("7", ["7", "7", "7", "7", "7", "7"]),
+        ];
+        let modes = [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+        ];
+        for (value, expected) in cases {
+            for (mode, result) in modes.iter().zip(expected.iter()) {
+                assert_eq!(
+                    dec(value).with_scale(0, *mode).to_string(),
+                    *result,
+                    "{} {:?}",
+                    value,
+                    mode
+                );
+            }
+        }
+        let quotient = dec("-2").div_with_scale(&dec("3"), 2, RoundingMode::Floor);
+        assert_eq!(quotient.to_string(), "-0.67");
+    }
+}
@@ -0,0 +1,51 @@
+mod common;
+
+#[cfg(test)]
+mod division_tests {
+    use crate::common::Lcg;
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_divide_small() {
+        let g = Grou::from(vec![0, 1]);
+        assert_eq!(g.div_rem_small(3), (Grou::from(6148914691236517205), 1));
+        assert_eq!(&g / 2, Grou::from(1 << 63));
+        assert_eq!(&g % 10, 6);
+        assert_eq!(Grou::from(0) / 7, Grou::from(0));
+    }
+
+    #[test]
+    fn test_divide() {
+        let n = Grou::from(vec![50, 125, 50]);
+        let d = Grou::from(vec![5, 10]);
+        assert_eq!(n.div_rem(&d), (Grou::from(vec![10, 5]), Grou::from(0)));
+
+        let n = Grou::from(vec![51, 125, 50]);
+        assert_eq!(&n / &d, Grou::from(vec![10, 5]));
+        assert_eq!(&n % &d, Grou::from(1));
+
+        assert_eq!(d.div_rem(&n), (Grou::from(0), d.clone()));
+    }
+
+    #[test]
+    fn test_divide_invariant() {
+        let mut rng = Lcg(0x1234_5678_9abc_def0);
+        for length in 2..12 {
+            let n = Grou::from(rng.blocks(2 * length));
+            let mut divisor = rng.blocks(length);
+            // Exercise the unnormalized and add-back paths.
+            divisor[length - 1] >>= length * 5;
+            let d = Grou::from(divisor);
+
+            let (q, r) = n.div_rem(&d);
+            assert!(r < d);
+            assert_eq!(&q * &d + &r, n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_divide_by_zero() {
+        let _ = Grou::from(5) / Grou::from(vec![0, 0]);
+    }
+}
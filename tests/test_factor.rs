@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod factor_tests {
+    use grou_num::factor::{pollard_p_minus_1, pollard_rho_brent};
+    use grou_num::grou::Grou;
+
+    fn factor_naive(mut n: u64) -> Vec<(Grou, u32)> {
+        let mut factors = Vec::new();
+        let mut d = 2;
+        while d * d <= n {
+            let mut count = 0;
+            while n.is_multiple_of(d) {
+                n /= d;
+                count += 1;
+            }
+            if count > 0 {
+                factors.push((Grou::from(d), count));
+            }
+            d += 1;
+        }
+        if n > 1 {
+            factors.push((Grou::from(n), 1));
+        }
+        factors
+    }
+
+    fn product(factors: &[(Grou, u32)]) -> Grou {
+        factors
+            .iter()
+            .fold(Grou::from(1), |acc, (p, e)| acc * p.pow(*e))
+    }
+
+    #[test]
+    fn test_factor_small() {
+        assert_eq!(Grou::from(1).factor(), vec![]);
+        for n in 2u64..3000 {
+            assert_eq!(Grou::from(n).factor(), factor_naive(n), "factor({})", n);
+        }
+        let n = 1000003u64 * 1000033 * 1000037;
+        assert_eq!(Grou::from(n).factor(), factor_naive(n));
+    }
+
+    #[test]
+    fn test_factor_large() {
+        // (2^61 - 1) * 1000000007^2 * 3^5
+        let m61 = Grou::from((1 << 61) - 1);
+        let p = Grou::from(1000000007);
+        let n = &m61 * &p.pow(2) * Grou::from(243);
+        assert_eq!(
+            n.factor(),
+            vec![(Grou::from(3), 5), (p, 2), (m61, 1)]
+        );
+
+        // Two 40 bit primes.
+        let p = Grou::from(1099511627791);
+        let q = Grou::from(1099511628401);
+        let n = &p * &q;
+        let factors = n.factor();
+        assert_eq!(factors, vec![(p, 1), (q, 1)]);
+        assert_eq!(product(&factors), n);
+    }
+
+    #[test]
+    fn test_pollard_p_minus_1() {
+        // p - 1 = 2 * 23 * 47 * 71 * 83 * 89 * 97^2 is 9409-smooth, while
+        // q - 1 has a prime factor above a million.
+        let p = Grou::from(10669046449067);
+        let q = Grou::from(35184372088907);
+        let n = &p * &q;
+        assert_eq!(pollard_p_minus_1(&n, 10000), Some(p));
+        assert_eq!(pollard_p_minus_1(&n, 9000), None);
+    }
+
+    #[test]
+    fn test_pollard_rho_brent() {
+        let n = Grou::from(1000003u64 * 1000033);
+        let divisor = (1..).find_map(|c| pollard_rho_brent(&n, c)).unwrap();
+        assert!(divisor == Grou::from(1000003) || divisor == Grou::from(1000033));
+    }
+}
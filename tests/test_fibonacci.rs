@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod fibonacci_tests {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_fibonacci_against_additions() {
+        let mut x = Grou::from(0);
+        let mut y = Grou::from(1);
+        for n in 0..1500u64 {
+            assert_eq!(Grou::fibonacci(n), x, "F({})", n);
+            let z = &x + &y;
+            x = y;
+            y = z;
+        }
+    }
+
+    #[test]
+    fn test_fib_pair() {
+        assert_eq!(Grou::fib_pair(0), (Grou::from(0), Grou::from(1)));
+        assert_eq!(Grou::fib_pair(10), (Grou::from(55), Grou::from(89)));
+        assert_eq!(Grou::fibonacci(93), Grou::from(12200160415121876738));
+
+        // Cassini: F(n - 1) F(n + 1) - F(n)^2 = (-1)^n
+        let (f_n, f_next) = Grou::fib_pair(10001);
+        let f_prev = &f_next - &f_n;
+        assert_eq!(&f_prev * &f_next + Grou::from(1), &f_n * &f_n);
+    }
+
+    #[test]
+    fn test_lucas() {
+        let expected = [2u64, 1, 3, 4, 7, 11, 18, 29, 47, 76, 123];
+        for (n, value) in expected.iter().enumerate() {
+            assert_eq!(Grou::lucas(n as u64), Grou::from(*value));
+        }
+
+        // L(2n) = L(n)^2 - 2(-1)^n
+        let l_n = Grou::lucas(5000);
+        assert_eq!(Grou::lucas(10000), &l_n * &l_n - Grou::from(2));
+    }
+}
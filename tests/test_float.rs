@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod float_tests {
+    use grou_num::float::GrouFloat;
+    use grou_num::grou::Grou;
+    use grou_num::rounding::RoundingMode;
+
+    fn float(x: f64) -> GrouFloat {
+        GrouFloat::from_f64(x, 53, RoundingMode::HalfEven)
+    }
+
+    #[test]
+    fn test_f64_round_trip() {
+        let values = [
+            0.0,
+            1.0,
+            -1.5,
+            0.1,
+            std::f64::consts::PI,
+            1e300,
+            -1e-300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            1.2345e-310,
+        ];
+        for x in values {
+            assert_eq!(float(x).to_f64(), x);
+        }
+        let huge = GrouFloat::new(false, Grou::from(1), 2000, 53, RoundingMode::HalfEven);
+        assert_eq!(huge.to_f64(), f64::INFINITY);
+        let tiny = GrouFloat::new(true, Grou::from(1), -2000, 53, RoundingMode::HalfEven);
+        assert_eq!(tiny.to_f64(), 0.0);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_f64() {
+        // f64 operations are correctly rounded to nearest-even at 53 bits.
+        let values = [1.0, 3.0, -0.1, 7.25, 1e20, -3e-15, 123456.789];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let (x, y) = (float(a), float(b));
+                assert_eq!((&x + &y).to_f64(), a + b, "{} + {}", a, b);
+                assert_eq!((&x - &y).to_f64(), a - b, "{} - {}", a, b);
+                assert_eq!((&x * &y).to_f64(), a * b, "{} * {}", a, b);
+                assert_eq!((&x / &y).to_f64(), a / b, "{} / {}", a, b);
+            }
+            assert_eq!(float(a.abs()).sqrt().to_f64(), a.abs().sqrt());
+        }
+        assert!(float(1e-300) < float(2.0));
+        assert!(float(-3.0) < float(0.0));
+        assert_eq!(float(0.5) + float(-0.5), float(0.0));
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        // 1 / 3 at 4 bits lies between 0.01010b and 0.01011b, nearer the
+        // second.
+        let one = GrouFloat::from_f64(1.0, 4, RoundingMode::HalfEven);
+        let three = GrouFloat::from_f64(3.0, 4, RoundingMode::HalfEven);
+        let third = |mode| one.div_with(&three, 4, mode).to_f64();
+        assert_eq!(third(RoundingMode::HalfEven), 0.34375);
+        assert_eq!(third(RoundingMode::TowardZero), 0.3125);
+        assert_eq!(third(RoundingMode::Floor), 0.3125);
+        assert_eq!(third(RoundingMode::Ceiling), 0.34375);
+        let minus_third = |mode| (-&one).div_with(&three, 4, mode).to_f64();
+        assert_eq!(minus_third(RoundingMode::Floor), -0.34375);
+        assert_eq!(minus_third(RoundingMode::Ceiling), -0.3125);
+
+        // A tiny addend only decides the direction of the rounding.
+        let big = GrouFloat::new(false, Grou::from(1), 1000, 10, RoundingMode::HalfEven);
+        let tiny = GrouFloat::new(false, Grou::from(1), -1000, 10, RoundingMode::HalfEven);
+        assert_eq!(big.add_with(&tiny, 10, RoundingMode::HalfEven), big);
+        let up = big.add_with(&tiny, 10, RoundingMode::Ceiling);
+        assert_eq!(
+            up,
+            GrouFloat::new(false, Grou::from(513), 991, 10, RoundingMode::HalfEven)
+        );
+        let down = big.sub_with(&tiny, 10, RoundingMode::TowardZero);
+        assert_eq!(
+            down,
+            GrouFloat::new(false, Grou::from(1023), 990, 10, RoundingMode::HalfEven)
+        );
+    }
+
+    #[test]
+    fn test_decimal_output() {
+        assert_eq!(
+            float(0.1).to_string(),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+        assert_eq!(format!("{:.5}", float(-0.1)), "-0.10000");
+        assert_eq!(format!("{:.2}", float(2.675)), "2.67");
+        assert_eq!(float(1e20).to_string(), "100000000000000000000");
+
+        let two = GrouFloat::from_f64(2.0, 200, RoundingMode::HalfEven);
+        assert_eq!(
+            format!("{:.50}", two.sqrt()),
+            "1.41421356237309504880168872420969807856967187537695"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod gcd_tests {
+    use grou_num::grou::Grou;
+    use grou_num::signed::GrouCoeff;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(Grou::from(12).gcd(&Grou::from(18)), Grou::from(6));
+        assert_eq!(Grou::from(0).gcd(&Grou::from(18)), Grou::from(18));
+        assert_eq!(Grou::from(0).gcd(&Grou::from(0)), Grou::from(0));
+
+        let a = Grou::from(vec![0, 0, 6]);
+        let b = Grou::from(vec![0, 4]);
+        assert_eq!(a.gcd(&b), Grou::from(vec![0, 4]));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let a = Grou::from(240);
+        let b = Grou::from(46);
+        let (g, x, y) = a.extended_gcd(&b);
+        assert_eq!(g, Grou::from(2));
+        assert_eq!(x, GrouCoeff::from(-9));
+        assert_eq!(y, GrouCoeff::from(47));
+
+        let a = Grou::from(vec![u64::MAX, 12345, 7]);
+        let b = Grou::from(vec![987654321, u64::MAX]);
+        let (g, x, y) = a.extended_gcd(&b);
+        let lhs = GrouCoeff::from(a.clone()) * &x + GrouCoeff::from(b.clone()) * &y;
+        assert_eq!(lhs, GrouCoeff::from(g.clone()));
+        assert_eq!(g, a.gcd(&b));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(Grou::from(3).mod_inverse(&Grou::from(11)), Some(Grou::from(4)));
+        assert_eq!(Grou::from(6).mod_inverse(&Grou::from(9)), None);
+        assert_eq!(Grou::from(6).mod_inverse(&Grou::from(0)), None);
+        assert_eq!(Grou::from(6).mod_inverse(&Grou::from(1)), Some(Grou::from(0)));
+
+        // 2^127 - 1 is prime.
+        let p = Grou::from(vec![u64::MAX, u64::MAX >> 1]);
+        let a = Grou::from(vec![123456789, 42]);
+        let inverse = a.mod_inverse(&p).unwrap();
+        assert_eq!((&a * &inverse) % &p, Grou::from(1));
+    }
+}
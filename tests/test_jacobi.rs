@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod jacobi_tests {
+    use grou_num::grou::Grou;
+
+    // Euler's criterion: a^((p - 1) / 2) mod p.
+    fn legendre_naive(a: u64, p: u64) -> i8 {
+        let mut result = 1u64;
+        for _ in 0..(p - 1) / 2 {
+            result = result * a % p;
+        }
+        match result {
+            0 => 0,
+            1 => 1,
+            _ => -1,
+        }
+    }
+
+    #[test]
+    fn test_legendre() {
+        for p in [3u64, 5, 7, 11, 13, 101, 251] {
+            for a in 0..3 * p {
+                assert_eq!(
+                    Grou::from(a).legendre(&Grou::from(p)),
+                    legendre_naive(a % p, p),
+                    "({} / {})",
+                    a,
+                    p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi() {
+        // The Jacobi symbol is multiplicative in n.
+        let primes = [3u64, 5, 7, 11, 13];
+        for (ind, p) in primes.iter().enumerate() {
+            for q in primes[ind..].iter() {
+                for a in 0..200u64 {
+                    assert_eq!(
+                        Grou::from(a).jacobi(&Grou::from(p * q)),
+                        legendre_naive(a % p, *p) * legendre_naive(a % q, *q),
+                        "({} / {})",
+                        a,
+                        p * q
+                    );
+                }
+            }
+        }
+        assert_eq!(Grou::from(5).jacobi(&Grou::from(1)), 1);
+        assert_eq!(Grou::from(1001).jacobi(&Grou::from(9907)), -1);
+        assert_eq!(Grou::from(19).jacobi(&Grou::from(45)), 1);
+        assert_eq!(Grou::from(8).jacobi(&Grou::from(21)), -1);
+
+        // 2^127 - 1 is 7 mod 8, so 2 is a square modulo it, and -1 is not.
+        let p = Grou::from(vec![u64::MAX, u64::MAX >> 1]);
+        assert_eq!(Grou::from(2).jacobi(&p), 1);
+        assert_eq!((&p - Grou::from(1)).jacobi(&p), -1);
+        assert_eq!(Grou::from(vec![5, 7, 9]).pow(2).jacobi(&p), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jacobi_even() {
+        Grou::from(3).jacobi(&Grou::from(10));
+    }
+
+    #[test]
+    fn test_kronecker() {
+        assert_eq!(Grou::from(1).kronecker(&Grou::from(0)), 1);
+        assert_eq!(Grou::from(2).kronecker(&Grou::from(0)), 0);
+        assert_eq!(Grou::from(4).kronecker(&Grou::from(6)), 0);
+        for (a, expected) in [(1u64, 1i8), (3, -1), (5, -1), (7, 1), (9, 1)] {
+            assert_eq!(Grou::from(a).kronecker(&Grou::from(2)), expected);
+        }
+        // (3 / 12) = (3 / 2)^2 (3 / 3) = 0 and (5 / 12) = (5 / 2)^2 (5 / 3) = -1.
+        assert_eq!(Grou::from(3).kronecker(&Grou::from(12)), 0);
+        assert_eq!(Grou::from(5).kronecker(&Grou::from(12)), -1);
+        assert_eq!(Grou::from(7).kronecker(&Grou::from(24)), 1);
+        assert_eq!(Grou::from(13).kronecker(&Grou::from(24)), -1);
+        assert_eq!(Grou::from(11).kronecker(&Grou::from(15)), Grou::from(11).jacobi(&Grou::from(15)));
+
+        // A Grou with no blocks is 0.
+        let empty = Grou::from(vec![]);
+        assert_eq!(empty.kronecker(&Grou::from(1)), 1);
+        assert_eq!(empty.kronecker(&Grou::from(3)), 0);
+        assert_eq!(empty.kronecker(&Grou::from(0)), 0);
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod limbs_tests {
+    use grou_num::limbs;
+    use std::cmp::Ordering;
+
+    const M: u64 = u64::MAX;
+
+    #[test]
+    fn test_add_sub() {
+        let mut r = [0u64; 3];
+        assert_eq!(limbs::add_n(&mut r, &[M, M, 1], &[1, 0, 2]), 0);
+        assert_eq!(r, [0, 0, 4]);
+        assert_eq!(limbs::add_n(&mut r, &[0, 0, M], &[0, 0, 1]), 1);
+        assert_eq!(r, [0, 0, 0]);
+
+        assert_eq!(limbs::sub_n(&mut r, &[0, 0, 4], &[1, 0, 2]), 0);
+        assert_eq!(r, [M, M, 1]);
+        assert_eq!(limbs::sub_n(&mut r, &[0, 0, 0], &[1, 0, 0]), 1);
+        assert_eq!(r, [M, M, M]);
+
+        assert_eq!(limbs::add_1(&mut r, &[M, M, 5], 1), 0);
+        assert_eq!(r, [0, 0, 6]);
+        assert_eq!(limbs::add_1_assign(&mut r, M), 0);
+        assert_eq!(r, [M, 0, 6]);
+        assert_eq!(limbs::sub_1_assign(&mut r, M), 0);
+        assert_eq!(limbs::sub_1_assign(&mut r, 1), 0);
+        assert_eq!(r, [M, M, 5]);
+
+        let mut r = [M, 7];
+        assert_eq!(limbs::add_n_assign(&mut r, &[1, M - 7]), 1);
+        assert_eq!(r, [0, 0]);
+        assert_eq!(limbs::sub_n_assign(&mut r, &[1, 0]), 1);
+        assert_eq!(r, [M, M]);
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut r = [0u64; 3];
+        assert_eq!(limbs::mul_1(&mut r, &[M, M, M], M), M - 1);
+        assert_eq!(r, [1, M, M]);
+
+        // (2^192 - 1) * (2^64 - 1) + 2^192 - 2^64 + 1 overflows by M.
+        assert_eq!(limbs::addmul_1(&mut r, &[M, M, M], M), M);
+        assert_eq!(r, [2, M - 1, M]);
+
+        // Taking the same product away again restores r, with the borrow
+        // matching the carry.
+        assert_eq!(limbs::submul_1(&mut r, &[M, M, M], M), M);
+        assert_eq!(r, [1, M, M]);
+        assert_eq!(limbs::submul_1(&mut r, &[1, 0, 0], 2), 0);
+        assert_eq!(r, [M, M - 1, M]);
+    }
+
+    #[test]
+    fn test_shift_cmp() {
+        let mut r = [0u64; 2];
+        assert_eq!(limbs::lshift(&mut r, &[1 << 63 | 1, 3 << 62], 1), 1);
+        assert_eq!(r, [2, 1 << 63 | 1]);
+        assert_eq!(limbs::rshift(&mut r, &[5, 1], 1), 1 << 63);
+        assert_eq!(r, [1 << 63 | 2, 0]);
+        assert_eq!(limbs::lshift(&mut r, &[5, 6], 0), 0);
+        assert_eq!(r, [5, 6]);
+
+        assert_eq!(limbs::cmp(&[5, 6], &[7, 5]), Ordering::Greater);
+        assert_eq!(limbs::cmp(&[5, 6], &[7, 6]), Ordering::Less);
+        assert_eq!(limbs::cmp(&[5, 6], &[5, 6]), Ordering::Equal);
+        assert_eq!(limbs::cmp(&[], &[]), Ordering::Equal);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        let mut r = [0u64; 2];
+        limbs::add_n(&mut r, &[1, 2], &[3]);
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod modpow_tests {
+    use grou_num::grou::Grou;
+    use grou_num::modular::Montgomery;
+
+    #[test]
+    fn test_modpow_small() {
+        for modulus in [1u64, 2, 7, 10, 97, 1 << 40, (1 << 61) - 1] {
+            for base in [0u64, 1, 2, 3, 12345] {
+                for exp in [0u64, 1, 2, 5, 100, 65537] {
+                    let mut expected = 1u128 % modulus as u128;
+                    for _ in 0..exp {
+                        expected = expected * base as u128 % modulus as u128;
+                    }
+                    assert_eq!(
+                        Grou::from(base).modpow(&Grou::from(exp), &Grou::from(modulus)),
+                        Grou::from(expected as u64),
+                        "{}^{} mod {}",
+                        base,
+                        exp,
+                        modulus
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_modpow_fermat() {
+        // 2^127 - 1 is prime, so a^(p - 1) = 1 mod p.
+        let p = Grou::from(vec![u64::MAX, u64::MAX >> 1]);
+        let exp = &p - Grou::from(1);
+        let base = Grou::from(vec![0xdeadbeef, 0xcafebabe, 0x1234]);
+        assert_eq!(base.modpow(&exp, &p), Grou::from(1));
+
+        // Even modulus: 3^(2^64) mod 2^128, checked against repeated squaring.
+        let modulus = Grou::from(vec![0, 0, 1]);
+        let mut expected = Grou::from(3);
+        for _ in 0..64 {
+            expected = (&expected * &expected) % &modulus;
+        }
+        assert_eq!(Grou::from(3).modpow(&Grou::from(vec![0, 1]), &modulus), expected);
+    }
+
+    #[test]
+    fn test_montgomery_roundtrip() {
+        assert!(Montgomery::new(&Grou::from(10)).is_none());
+
+        let modulus = Grou::from(vec![0x1234567890abcdef, 0xfedcba0987654321, 3]);
+        let context = Montgomery::new(&modulus).unwrap();
+        let a = Grou::from(vec![5, 6, 2]);
+        let b = Grou::from(vec![u64::MAX, 7]);
+
+        let a_m = context.to_montgomery(&a);
+        let b_m = context.to_montgomery(&b);
+        assert_eq!(context.from_montgomery(&a_m), a);
+        assert_eq!(
+            context.from_montgomery(&context.mul(&a_m, &b_m)),
+            (&a * &b) % &modulus
+        );
+    }
+}
+
+#[cfg(test)]
+mod sqrt_mod_prime_tests {
+    use grou_num::grou::Grou;
+
+    fn check_all_residues(p: u64) {
+        let p_grou = Grou::from(p);
+        let mut is_square = vec![false; p as usize];
+        for x in 0..p {
+            is_square[(x * x % p) as usize] = true;
+        }
+        for a in 0..p {
+            let root = Grou::from(a).sqrt_mod_prime(&p_grou);
+            if is_square[a as usize] {
+                let root = root.unwrap();
+                assert!(root <= &p_grou - &root, "sqrt({}) mod {}", a, p);
+                assert_eq!((&root * &root) % &p_grou, Grou::from(a), "sqrt({}) mod {}", a, p);
+            } else {
+                assert_eq!(root, None, "sqrt({}) mod {}", a, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_small_primes() {
+        // 3 and 7 mod 8, 5 mod 8, and 1 mod 8 with increasing powers of 2
+        // in p - 1.
+        for p in [2u64, 3, 7, 11, 5, 13, 29, 17, 41, 97, 193, 257, 7681] {
+            check_all_residues(p);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_large_primes() {
+        // 2^127 - 1 = 7 mod 8, 2^255 - 19 = 5 mod 8, and
+        // 2^64 - 2^32 + 1 = 1 mod 2^32.
+        let primes = [
+            Grou::from(vec![u64::MAX, u64::MAX >> 1]),
+            Grou::from(vec![u64::MAX - 18, u64::MAX, u64::MAX, u64::MAX >> 1]),
+            Grou::from(0xffff_ffff_0000_0001),
+        ];
+        for p in primes.iter() {
+            for x in [Grou::from(2), Grou::from(vec![12345, 678]), p - Grou::from(3)] {
+                let square = (&x * &x) % p;
+                let root = square.sqrt_mod_prime(p).unwrap();
+                let reduced = &x % p;
+                assert!(root == reduced || root == p - &reduced);
+            }
+            let non_residue = (2u64..).map(Grou::from).find(|z| z.legendre(p) == -1).unwrap();
+            assert_eq!(non_residue.sqrt_mod_prime(p), None);
+        }
+    }
+}
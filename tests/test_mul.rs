@@ -0,0 +1,78 @@
+mod common;
+
+#[cfg(test)]
+mod mul_kernel_tests {
+    use crate::common::{mul_schoolbook, Lcg};
+    use grou_num::grou::Grou;
+    use grou_num::mul::{mul_into, mul_scratch_len, KARATSUBA_THRESHOLD};
+
+    #[test]
+    fn test_against_schoolbook() {
+        let mut rng = Lcg(7);
+        let t = KARATSUBA_THRESHOLD;
+        let lengths = [
+            (1, 1),
+            (t, t),
+            (t + 1, t),
+            (2 * t + 1, t + 1),
+            (3 * t, t),
+            (5 * t + 3, 2 * t - 1),
+            (300, 299),
+            (517, 64),
+            (40, 700),
+        ];
+        // One scratch buffer, sized for the largest product, for every call.
+        let scratch_len = lengths
+            .iter()
+            .map(|(a, b)| mul_scratch_len(*a, *b))
+            .max()
+            .unwrap();
+        let mut scratch = vec![0u64; scratch_len];
+
+        for (a_len, b_len) in lengths {
+            let a = rng.blocks(a_len);
+            let b = rng.blocks(b_len);
+            let mut out = vec![0u64; a_len + b_len];
+            mul_into(&mut out, &a, &b, &mut scratch);
+            assert_eq!(out, mul_schoolbook(&a, &b), "{} x {}", a_len, b_len);
+        }
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        // All-ones operands maximize the carries, and operands with equal
+        // halves make the Karatsuba differences 0.
+        for length in [KARATSUBA_THRESHOLD, 2 * KARATSUBA_THRESHOLD + 1, 200] {
+            let ones = vec![u64::MAX; length];
+            let mut halves = vec![5u64; length];
+            halves[length - 1] = 0;
+            for (a, b) in [(&ones, &ones), (&halves, &ones), (&halves, &halves)] {
+                let mut out = vec![0u64; 2 * length];
+                let mut scratch = vec![0u64; mul_scratch_len(length, length)];
+                mul_into(&mut out, a, b, &mut scratch);
+                assert_eq!(out, mul_schoolbook(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_grou_mul() {
+        let mut rng = Lcg(11);
+        let a = Grou::from(rng.blocks(150));
+        let b = Grou::from(rng.blocks(90));
+        let product = &a * &b;
+        assert_eq!(&product / &b, a);
+        assert_eq!(&product % &b, Grou::from(0));
+        assert_eq!(Grou::from(vec![3, 0, 0]) * Grou::from(vec![5, 0]), Grou::from(15));
+        assert_eq!(Grou::from(vec![]) * Grou::from(5), Grou::from(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scratch_too_small() {
+        let length = 2 * KARATSUBA_THRESHOLD;
+        let mut out = vec![0u64; 2 * length];
+        let mut scratch = vec![0u64; mul_scratch_len(length, length) - 1];
+        mul_into(&mut out, &vec![1; length], &vec![1; length], &mut scratch);
+    }
+}
@@ -60,6 +60,29 @@ mod addition_tests {
         assert_eq!(Grou::from(vec![u, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1]), g);
     }
 
+    #[test]
+    fn test_add_assign_lengths() {
+        let u = u64::MAX;
+
+        // The carry runs through the part of self past other.
+        let mut g = Grou::from(vec![u, u, u, 7]);
+        g += &Grou::from(vec![1]);
+        assert_eq!(Grou::from(vec![0, 0, 0, 8]), g);
+
+        // other is longer than self.
+        let mut g = Grou::from(vec![u, u]);
+        g += Grou::from(vec![1, 0, u, 2, 3, 4]);
+        assert_eq!(Grou::from(vec![0, 0, 0, 3, 3, 4]), g);
+
+        // Same result as Add, including the final carry.
+        let x = Grou::from(vec![u, u, u, u, u, u]);
+        let y = Grou::from(vec![u, 1]);
+        let mut g = x.clone();
+        g += &y;
+        assert_eq!(&x + &y, g);
+        assert_eq!(g.len(), 7);
+    }
+
     #[test]
     fn test_addition_grousubset_grou() {
         use grou_num::grou::Grou;
@@ -229,3 +252,77 @@ mod multiplication {
         assert_eq!(r3, Grou::from(vec![50, 200, 300, 200, 50]));
     }
 }
+
+#[cfg(test)]
+mod karatsuba_sign {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_karatsuba_mixed_signs() {
+        // a1 < a0 and b1 > b0, so the middle product changes sign.
+        let a = Grou::from(vec![5, 10]);
+        let b = Grou::from(vec![10, 5]);
+        assert_eq!(a.karatsuba_mul(&b), Grou::from(vec![50, 125, 50]));
+        assert_eq!(b.karatsuba_mul(&a), Grou::from(vec![50, 125, 50]));
+        assert_eq!(Grou::from(3) * Grou::from(4), Grou::from(12));
+    }
+
+    #[test]
+    fn test_multiply_straight_carry_offset() {
+        // The final carry of each row has to land past the row's offset.
+        let u = u64::MAX;
+        let g1 = Grou::from(vec![u, u]);
+        let g2 = Grou::from(vec![u, u]);
+        assert_eq!(
+            &g1.subset_all() * &g2.subset_all(),
+            Grou::from(vec![1, 0, u - 1, u])
+        );
+        assert_eq!(
+            Grou::from(vec![u]) * Grou::from(vec![u, u]),
+            Grou::from(vec![1, u, u - 1])
+        );
+    }
+}
+
+#[cfg(test)]
+mod inline_storage {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_grow_past_inline() {
+        // Carries that push a fourth and fifth block.
+        let mut x = Grou::from(vec![u64::MAX, u64::MAX, u64::MAX]);
+        x += 1u64;
+        assert_eq!(x, Grou::from(vec![0, 0, 0, 1]));
+        x += &Grou::from(vec![0, 0, 0, u64::MAX]);
+        assert_eq!(x, Grou::from(vec![0, 0, 0, 0, 1]));
+        assert_eq!(x.len(), 5);
+
+        let mut y = Grou::from(vec![1, 2, 3, u64::MAX]);
+        y *= 2u64;
+        assert_eq!(y, Grou::from(vec![2, 4, 6, u64::MAX - 1, 1]));
+        assert_eq!(&y >> 64, Grou::from(vec![4, 6, u64::MAX - 1, 1]));
+    }
+
+    #[test]
+    fn test_clone_and_compare() {
+        // Equality only looks at the blocks, however they are stored.
+        let long = Grou::from(vec![7, 1, 2, 0, 0, 9]);
+        let short = Grou::from(vec![7, 1, 2]);
+        assert_eq!(long.clone(), long);
+        assert_eq!(short.clone(), short);
+        assert_ne!(long, short);
+
+        // The difference spilled to the heap with long, and keeps its
+        // allocation after trimming to three blocks.
+        let shrunk = &long - &Grou::from(vec![0, 0, 0, 0, 0, 9]);
+        assert_eq!(shrunk, short);
+        assert_eq!(short, shrunk);
+        assert_eq!(shrunk.clone(), short);
+        assert_eq!(format!("{:?}", Grou::from(vec![1, 2])), "Grou { data: [1, 2] }");
+
+        let product = Grou::from(vec![u64::MAX; 3]) * Grou::from(vec![u64::MAX; 3]);
+        assert_eq!(product, Grou::from(vec![1, 0, 0, u64::MAX - 1, u64::MAX, u64::MAX]));
+        assert_eq!(&product - &product, Grou::from(0));
+    }
+}
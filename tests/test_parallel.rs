@@ -0,0 +1,58 @@
+// These run with and without the `parallel` feature, and check that both
+// give the serial results.
+mod common;
+
+#[cfg(test)]
+mod parallel_tests {
+    use crate::common::{grou_trimmed, mul_schoolbook, Lcg};
+    use grou_num::grou::Grou;
+    use grou_num::mul::{mul_into, mul_scratch_len, PARALLEL_THRESHOLD};
+    use grou_num::toom::TOOM_8_THRESHOLD;
+
+    #[test]
+    fn test_karatsuba_above_threshold() {
+        let mut rng = Lcg(13);
+        for (a_len, b_len) in [
+            (PARALLEL_THRESHOLD, PARALLEL_THRESHOLD),
+            (2 * PARALLEL_THRESHOLD + 5, 2 * PARALLEL_THRESHOLD - 3),
+        ] {
+            let a = rng.blocks(a_len);
+            let b = rng.blocks(b_len);
+            let mut out = vec![0u64; a_len + b_len];
+            let mut scratch = vec![0u64; mul_scratch_len(a_len, b_len)];
+            mul_into(&mut out, &a, &b, &mut scratch);
+            assert_eq!(out, mul_schoolbook(&a, &b), "{} x {}", a_len, b_len);
+        }
+    }
+
+    #[test]
+    fn test_toom_above_threshold() {
+        let mut rng = Lcg(17);
+        let a = rng.blocks(TOOM_8_THRESHOLD + 100);
+        let b = rng.blocks(TOOM_8_THRESHOLD);
+        let expected = grou_trimmed(mul_schoolbook(&a, &b));
+        assert_eq!(Grou::from(a) * Grou::from(b), expected);
+    }
+
+    #[test]
+    fn test_concurrent_callers() {
+        // Several products at once compete for the same spare threads.
+        let mut rng = Lcg(19);
+        let operands: Vec<(Vec<u64>, Vec<u64>)> = (0..4)
+            .map(|_| {
+                (
+                    rng.blocks(2 * PARALLEL_THRESHOLD),
+                    rng.blocks(2 * PARALLEL_THRESHOLD),
+                )
+            })
+            .collect();
+        std::thread::scope(|scope| {
+            for (a, b) in operands.iter() {
+                scope.spawn(move || {
+                    let product = Grou::from(a.clone()).karatsuba_mul(&Grou::from(b.clone()));
+                    assert_eq!(product, grou_trimmed(mul_schoolbook(a, b)));
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+#[cfg(test)]
+mod primality_tests {
+    use grou_num::grou::Grou;
+
+    fn is_prime_naive(n: u64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn test_small_numbers() {
+        for n in 0u64..20000 {
+            let expected = is_prime_naive(n);
+            assert_eq!(Grou::from(n).is_prime_bpsw(), expected, "bpsw({})", n);
+            assert_eq!(
+                Grou::from(n).is_probable_prime(&[2, 3, 5, 7]),
+                expected,
+                "miller_rabin({})",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_pseudoprimes() {
+        // Strong pseudoprimes to base 2, without factors below 1000.
+        for n in [1678541u64, 3125281, 21359521] {
+            assert!(Grou::from(n).is_probable_prime(&[2]), "{}", n);
+            assert!(!Grou::from(n).is_probable_prime(&[2, 3]), "{}", n);
+            assert!(!Grou::from(n).is_prime_bpsw(), "{}", n);
+        }
+        // Carmichael numbers.
+        for n in [561u64, 41041, 825265, 321197185] {
+            assert!(!Grou::from(n).is_prime_bpsw(), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_large_primes() {
+        let m89 = Grou::from(vec![u64::MAX, (1 << 25) - 1]);
+        let m127 = Grou::from(vec![u64::MAX, u64::MAX >> 1]);
+        let m61 = Grou::from((1 << 61) - 1);
+        for p in [&m89, &m127, &m61] {
+            assert!(p.is_prime_bpsw());
+            assert!(p.is_probable_prime(&[2, 3, 5, 7, 11, 13]));
+        }
+
+        // Products of two large primes, and a square of one.
+        let composite = &m89 * &m61;
+        assert!(!composite.is_prime_bpsw());
+        assert!(!composite.is_probable_prime(&[2]));
+        assert!(!(&m61 * &m61).is_prime_bpsw());
+
+        // 2^128 + 51 is the smallest prime above 2^128.
+        let p = Grou::from(vec![51, 0, 1]);
+        assert!(p.is_prime_bpsw());
+        for offset in 1..51 {
+            let candidate = Grou::from(vec![offset, 0, 1]);
+            assert!(!candidate.is_prime_bpsw(), "2^128 + {}", offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod prime_generation_tests {
+    use crate::common::Lcg;
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_next_prev_prime() {
+        assert_eq!(Grou::from(0).next_prime(), Grou::from(2));
+        assert_eq!(Grou::from(2).next_prime(), Grou::from(3));
+        assert_eq!(Grou::from(3).next_prime(), Grou::from(5));
+        assert_eq!(Grou::from(997).next_prime(), Grou::from(1009));
+        assert_eq!(Grou::from(2).prev_prime(), None);
+        assert_eq!(Grou::from(3).prev_prime(), Some(Grou::from(2)));
+        assert_eq!(Grou::from(1009).prev_prime(), Some(Grou::from(997)));
+
+        // 2^64 - 59 and 2^64 + 13 are the primes around 2^64.
+        let power = Grou::from(vec![0, 1]);
+        assert_eq!(power.next_prime(), Grou::from(vec![13, 1]));
+        assert_eq!(power.prev_prime(), Some(Grou::from(u64::MAX - 58)));
+
+        // The gap after 1693182318746371 is 1132 long, more than one window.
+        let before_gap = Grou::from(1693182318746371);
+        let after_gap = Grou::from(1693182318746371 + 1132);
+        assert_eq!(before_gap.next_prime(), after_gap);
+        assert_eq!(after_gap.prev_prime(), Some(before_gap));
+    }
+
+    #[test]
+    fn test_random_prime() {
+        let mut rng = Lcg(42);
+        for bits in [2, 3, 10, 64, 65, 128, 200] {
+            let p = Grou::random_prime(bits, &mut rng);
+            assert_eq!(p.bit_length(), bits);
+            assert!(p.is_prime_bpsw());
+        }
+    }
+}
@@ -76,3 +76,46 @@ pub mod test_radix_convert {
         }
     }
 }
+
+#[cfg(test)]
+pub mod test_decimal_output {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_display() {
+        let inputs = [
+            "0",
+            "1234567890",
+            "10000000000000000000",
+            "1234567890123456789012345",
+            "123456789012345678901234567890",
+            "100000000000000000000000000000000000000000000000000000000001",
+        ];
+        for input in inputs {
+            assert_eq!(Grou::from(input).to_string(), input);
+        }
+        assert_eq!(Grou::from(vec![]).to_string(), "0");
+        assert_eq!(Grou::from(vec![0, 1, 0]).to_string(), "18446744073709551616");
+        assert_eq!(format!("{:>6}", Grou::from(42)), "    42");
+    }
+
+    #[test]
+    fn test_display_large() {
+        // Large enough to be split several times, with runs of zeros that
+        // must be kept as padding inside the split parts.
+        let mut inputs = vec![
+            format!("1{}", "0".repeat(2000)),
+            "9".repeat(3000),
+            format!("123{}456{}789", "0".repeat(700), "0".repeat(900)),
+            // One below and at a power used for splitting, whose reciprocal
+            // takes several Newton steps.
+            "9".repeat(19 * 1024),
+            format!("1{}", "0".repeat(19 * 1024)),
+        ];
+        let digits: String = (0..5000).map(|i| char::from(b'0' + ((i * 7 + i / 13) % 10) as u8)).collect();
+        inputs.push(format!("1{}", digits));
+        for input in inputs {
+            assert_eq!(Grou::from(&input[..]).to_string(), input);
+        }
+    }
+}
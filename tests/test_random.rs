@@ -0,0 +1,99 @@
+mod common;
+
+#[cfg(test)]
+mod random_tests {
+    use crate::common::Lcg;
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_random_bits() {
+        let mut rng = Lcg(1);
+        assert_eq!(Grou::random_bits(0, &mut rng), Grou::from(0));
+        let mut longest = 0;
+        for _ in 0..100 {
+            let g = Grou::random_bits(130, &mut rng);
+            assert!(g.bit_length() <= 130);
+            longest = std::cmp::max(longest, g.bit_length());
+        }
+        assert!(longest >= 125);
+    }
+
+    #[test]
+    fn test_random_below() {
+        let mut rng = Lcg(2);
+        let mut seen = [false; 7];
+        for _ in 0..200 {
+            let g = Grou::random_below(&Grou::from(7), &mut rng);
+            assert!(g < Grou::from(7));
+            seen[g.rem_small(7) as usize] = true;
+        }
+        assert!(seen.iter().all(|x| *x));
+
+        let bound = Grou::from(vec![5, 0, 1]);
+        for _ in 0..50 {
+            assert!(Grou::random_below(&bound, &mut rng) < bound);
+        }
+    }
+
+    #[test]
+    fn test_random_range() {
+        let mut rng = Lcg(3);
+        let low = Grou::from(vec![u64::MAX, 4]);
+        let high = Grou::from(vec![9, 5]);
+        for _ in 0..100 {
+            let g = Grou::random_range(&low, &high, &mut rng);
+            assert!(g >= low && g < high);
+        }
+        assert_eq!(
+            Grou::random_range(&Grou::from(10), &Grou::from(11), &mut rng),
+            Grou::from(10)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_range_empty() {
+        let mut rng = Lcg(4);
+        Grou::random_range(&Grou::from(10), &Grou::from(10), &mut rng);
+    }
+}
+
+// Any rand generator is a RngSource through the blanket impl, trait objects
+// included.
+#[cfg(all(test, feature = "rand"))]
+mod rand_tests {
+    use grou_num::grou::Grou;
+    use rand::rngs::SmallRng;
+    use rand::{RngCore, SeedableRng};
+
+    #[test]
+    fn test_seeded_rng() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let g = Grou::random_bits(200, &mut rng);
+        assert!(g.bit_length() <= 200);
+
+        let bound = Grou::from(vec![3, 0, 1]);
+        for _ in 0..50 {
+            assert!(Grou::random_below(&bound, &mut rng) < bound);
+        }
+
+        let p = Grou::random_prime(96, &mut rng);
+        assert_eq!(p.bit_length(), 96);
+        assert!(p.is_prime_bpsw());
+
+        // The same seed gives the same numbers.
+        let mut again = SmallRng::seed_from_u64(7);
+        assert_eq!(Grou::random_bits(200, &mut again), g);
+    }
+
+    #[test]
+    fn test_dyn_rng() {
+        let mut small = SmallRng::seed_from_u64(8);
+        let mut rng: &mut dyn RngCore = &mut small;
+        let g = Grou::random_below(&Grou::from(1000), &mut rng);
+        assert!(g < Grou::from(1000));
+        let p = Grou::random_prime(40, &mut rng);
+        assert_eq!(p.bit_length(), 40);
+        assert!(p.is_prime_bpsw());
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod ratio_tests {
+    use grou_num::grou::Grou;
+    use grou_num::ratio::GrouRatio;
+    use grou_num::signed::GrouCoeff;
+
+    fn ratio(num: i64, den: u64) -> GrouRatio {
+        GrouRatio::new(GrouCoeff::from(num), Grou::from(den))
+    }
+
+    #[test]
+    fn test_lowest_terms() {
+        let x = ratio(-6, 8);
+        assert_eq!(x.numer(), &GrouCoeff::from(-3));
+        assert_eq!(x.denom(), &Grou::from(4));
+        assert_eq!(ratio(0, 5), GrouRatio::from(0));
+        assert_eq!(ratio(10, 5), GrouRatio::from(2));
+        assert!(ratio(10, 5).is_integer());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = ratio(1, 3);
+        let b = ratio(-1, 6);
+        assert_eq!(&a + &b, ratio(1, 6));
+        assert_eq!(&a - &b, ratio(1, 2));
+        assert_eq!(&a * &b, ratio(-1, 18));
+        assert_eq!(&a / &b, ratio(-2, 1));
+        assert_eq!(b.recip(), GrouRatio::from(-6));
+        assert!(a > b);
+        assert!(-&a < b);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let cases = [
+            // (num, den, floor, ceil, round)
+            (7, 2, 3, 4, 4),
+            (-7, 2, -4, -3, -4),
+            (5, 3, 1, 2, 2),
+            (-5, 3, -2, -1, -2),
+            (4, 3, 1, 2, 1),
+            (-4, 3, -2, -1, -1),
+            (6, 3, 2, 2, 2),
+            (-6, 3, -2, -2, -2),
+        ];
+        for (num, den, floor, ceil, round) in cases {
+            let x = ratio(num, den);
+            assert_eq!(x.floor(), GrouCoeff::from(floor), "floor({})", x);
+            assert_eq!(x.ceil(), GrouCoeff::from(ceil), "ceil({})", x);
+            assert_eq!(x.round(), GrouCoeff::from(round), "round({})", x);
+        }
+    }
+
+    #[test]
+    fn test_parse_display() {
+        assert_eq!(GrouRatio::from("6/8"), ratio(3, 4));
+        assert_eq!(GrouRatio::from("-6/8"), ratio(-3, 4));
+        assert_eq!(GrouRatio::from("42"), GrouRatio::from(42));
+        assert_eq!(GrouRatio::from("3.1415"), ratio(6283, 2000));
+        assert_eq!(GrouRatio::from("-0.25"), ratio(-1, 4));
+        assert_eq!(ratio(-3, 4).to_string(), "-3/4");
+        assert_eq!(GrouRatio::from(5).to_string(), "5/1");
+
+        let large = "123456789012345678901234567891/7";
+        assert_eq!(GrouRatio::from(large).to_string(), large);
+    }
+
+    #[test]
+    #[should_panic(expected = "Input format is invalid.")]
+    fn test_parse_hex_decimal() {
+        // "0x1" would parse as an integer, but not as the whole part of a
+        // decimal.
+        let _ = GrouRatio::from("0x1.5");
+    }
+
+    #[test]
+    #[should_panic(expected = "Input format is invalid.")]
+    fn test_parse_garbage_decimal() {
+        let _ = GrouRatio::from("1a.5");
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod square_root_tests {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_isqrt_small() {
+        for n in 0u64..2000 {
+            let expected = (n as f64).sqrt() as u64;
+            assert_eq!(Grou::from(n).isqrt(), Grou::from(expected), "isqrt({})", n);
+        }
+        assert_eq!(Grou::from(u64::MAX).isqrt(), Grou::from(u32::MAX as u64));
+    }
+
+    #[test]
+    fn test_sqrt_rem() {
+        // (2^64 + 3)^2 + 5
+        let root = Grou::from(vec![3, 1]);
+        let n = &root * &root + Grou::from(5);
+        assert_eq!(n.sqrt_rem(), (root.clone(), Grou::from(5)));
+
+        let n = &root * &root;
+        assert_eq!(n.sqrt_rem(), (root, Grou::from(0)));
+
+        let large = Grou::from(vec![0x1234, 0xdeadbeef, 77, 1 << 40, 9]);
+        let (s, r) = large.sqrt_rem();
+        assert_eq!(&s * &s + &r, large);
+        let next = &s + Grou::from(1);
+        assert!(&next * &next > large);
+    }
+
+    #[test]
+    fn test_is_perfect_square() {
+        let squares: Vec<u64> = (0..200).map(|x| x * x).collect();
+        for n in 0u64..40000 {
+            assert_eq!(
+                Grou::from(n).is_perfect_square(),
+                squares.contains(&n),
+                "is_perfect_square({})",
+                n
+            );
+        }
+
+        let root = Grou::from(vec![u64::MAX, 17, 3]);
+        let square = &root * &root;
+        assert!(square.is_perfect_square());
+        assert!(!(square + Grou::from(1)).is_perfect_square());
+    }
+}
+
+#[cfg(test)]
+mod nth_root_tests {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_nth_root() {
+        assert_eq!(Grou::from(26).nth_root(3), Grou::from(2));
+        assert_eq!(Grou::from(27).nth_root(3), Grou::from(3));
+        assert_eq!(Grou::from(1000).nth_root(1), Grou::from(1000));
+        assert_eq!(Grou::from(0).nth_root(5), Grou::from(0));
+        assert_eq!(Grou::from(7).nth_root(5), Grou::from(1));
+
+        let base = Grou::from(vec![0xfedcba9876543210, 0x1234]);
+        for n in 3..8 {
+            let power = base.pow(n);
+            assert_eq!(power.nth_root(n), base);
+            assert_eq!((&power - Grou::from(1)).nth_root(n), &base - Grou::from(1));
+        }
+    }
+
+    #[test]
+    fn test_perfect_power() {
+        assert_eq!(Grou::from(64).perfect_power(), Some((Grou::from(2), 6)));
+        assert_eq!(Grou::from(1000).perfect_power(), Some((Grou::from(10), 3)));
+        assert_eq!(Grou::from(1001).perfect_power(), None);
+        assert_eq!(Grou::from(1).perfect_power(), None);
+        assert_eq!(Grou::from(2).perfect_power(), None);
+
+        let power = Grou::from(vec![12345, 1]).pow(15);
+        assert_eq!(power.perfect_power(), Some((Grou::from(vec![12345, 1]), 15)));
+        assert_eq!((power + Grou::from(2)).perfect_power(), None);
+    }
+}
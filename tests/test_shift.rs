@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod shift_tests {
+    use grou_num::grou::Grou;
+
+    #[test]
+    fn test_shift_left_right() {
+        let g = Grou::from(vec![u64::MAX, 1]);
+        assert_eq!(&g << 1, Grou::from(vec![u64::MAX - 1, 3]));
+        assert_eq!(&g << 64, Grou::from(vec![0, u64::MAX, 1]));
+        assert_eq!(&g << 65, Grou::from(vec![0, u64::MAX - 1, 3]));
+        assert_eq!(&g >> 1, Grou::from(vec![u64::MAX]));
+        assert_eq!(&g >> 64, Grou::from(1));
+        assert_eq!(&g >> 200, Grou::from(0));
+        assert_eq!((&g << 77) >> 77, g);
+    }
+
+    #[test]
+    fn test_bit_length() {
+        assert_eq!(Grou::from(0).bit_length(), 0);
+        assert_eq!(Grou::from(1).bit_length(), 1);
+        assert_eq!(Grou::from(vec![0, 1, 0]).bit_length(), 65);
+        assert_eq!(Grou::from(vec![0, 8]).trailing_zeros(), 67);
+        assert!(Grou::from(vec![0, 8]).bit(67));
+        assert!(!Grou::from(vec![0, 8]).bit(66));
+    }
+}
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod signed_tests {
+    use grou_num::grou::Grou;
+    use grou_num::signed::GrouCoeff;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = GrouCoeff::from(-7);
+        let b = GrouCoeff::from(3);
+        assert_eq!(&a + &b, GrouCoeff::from(-4));
+        assert_eq!(&b - &a, GrouCoeff::from(10));
+        assert_eq!(&a * &b, GrouCoeff::from(-21));
+        assert_eq!(&a * Grou::from(2), GrouCoeff::from(-14));
+        assert_eq!(-&a, GrouCoeff::from(7));
+        assert!(a < b && -&b < b);
+    }
+
+    #[test]
+    fn test_zero_and_rem_euclid() {
+        // 0 is never negative, however it is built.
+        assert_eq!(
+            GrouCoeff::new(true, Grou::from(vec![0, 0])),
+            GrouCoeff::from(0)
+        );
+        assert!(!(GrouCoeff::from(3) - GrouCoeff::from(3)).is_negative());
+
+        let modulus = Grou::from(5);
+        assert_eq!(GrouCoeff::from(-7).rem_euclid(&modulus), Grou::from(3));
+        assert_eq!(GrouCoeff::from(-10).rem_euclid(&modulus), Grou::from(0));
+        assert_eq!(GrouCoeff::from(12).rem_euclid(&modulus), Grou::from(2));
+    }
+}
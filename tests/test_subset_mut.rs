@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod subset_mut_tests {
+    use grou_num::grou::Grou;
+
+    const M: u64 = u64::MAX;
+
+    #[test]
+    fn test_carry_into_parent() {
+        let mut g = Grou::from(vec![1, M, M, 5]);
+        let other = Grou::from(vec![1]);
+        {
+            let mut view = g.subset_mut(1, 2);
+            assert_eq!(view.len(), 1);
+            assert!(!view.add_assign(other.subset_all()));
+        }
+        assert_eq!(g, Grou::from(vec![1, 0, 0, 6]));
+
+        // A borrow out of the view runs up to the top.
+        assert!(!g.subset_mut(0, 2).sub_assign(Grou::from(vec![2]).subset_all()));
+        assert_eq!(g, Grou::from(vec![M, M, M, 5]));
+
+        // Running off the top of the parent is reported.
+        let mut g = Grou::from(vec![M, M]);
+        assert!(g.subset_mut(0, 1).add_assign(Grou::from(1).subset_all()));
+        assert_eq!(g, Grou::from(vec![0, 0]));
+        assert!(g.subset_mut(1, 2).sub_assign(Grou::from(1).subset_all()));
+        assert_eq!(g, Grou::from(vec![0, M]));
+    }
+
+    #[test]
+    fn test_add_shifted() {
+        // Accumulate partial products at their offsets, as in Karatsuba.
+        let a = Grou::from(vec![3, M]);
+        let b = Grou::from(vec![M, 7]);
+        let mut product = Grou::from(vec![0; 4]);
+        let mut view = product.subset_mut(0, 4);
+        for (shift, b_val) in b.subset_all().data.iter().enumerate() {
+            let partial = a.clone() * Grou::from(*b_val);
+            assert!(!view.add_shifted(partial.subset_all(), shift));
+        }
+        assert_eq!(product, &a * &b);
+    }
+
+    #[test]
+    fn test_split_and_blocks() {
+        let mut g = Grou::from(vec![M, 1, 2, 3, 4]);
+        {
+            let (mut low, mut high) = g.split_at_mut(1);
+            assert_eq!(low.blocks(), &[M]);
+            assert_eq!(high.blocks(), &[1, 2, 3, 4]);
+            // The low half does not reach into the high one.
+            assert!(low.add_assign(Grou::from(1).subset_all()));
+            assert!(!high.add_assign(Grou::from(vec![M, 1]).subset_all()));
+        }
+        assert_eq!(g, Grou::from(vec![0, 0, 4, 3, 4]));
+
+        let blocks = g.blocks_mut(2);
+        assert_eq!(blocks.len(), 3);
+        for mut block in blocks.rev() {
+            block.blocks_mut().reverse();
+        }
+        assert_eq!(g, Grou::from(vec![0, 0, 3, 4, 4]));
+        assert_eq!(g.blocks_mut(0).len(), 0);
+        let expected = g.subset(2, 4).data.to_vec();
+        assert_eq!(g.subset_mut(2, 4).as_subset().data, &expected[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds() {
+        let mut g = Grou::from(vec![1, 2]);
+        g.subset_mut(1, 3);
+    }
+}
@@ -0,0 +1,96 @@
+mod common;
+
+#[cfg(test)]
+mod toom_tests {
+    use crate::common::{grou_trimmed, Lcg};
+    use grou_num::grou::Grou;
+    use grou_num::mul::{mul_into, mul_scratch_len};
+    use grou_num::toom::{ToomScheme, TOOM_4_THRESHOLD, TOOM_8_THRESHOLD};
+
+    // The product from the Karatsuba kernels, which never use Toom.
+    fn mul_reference(a: &[u64], b: &[u64]) -> Grou {
+        let mut out = vec![0u64; a.len() + b.len()];
+        let mut scratch = vec![0u64; mul_scratch_len(a.len(), b.len())];
+        mul_into(&mut out, a, b, &mut scratch);
+        grou_trimmed(out)
+    }
+
+    #[test]
+    fn test_schemes_against_reference() {
+        let mut rng = Lcg(3);
+        let lengths = [
+            (1, 1),
+            (5, 3),
+            (8, 8),
+            (17, 16),
+            (64, 61),
+            (100, 40),
+            (30, 250),
+        ];
+        for (a_len, b_len) in lengths {
+            let a = Grou::from(rng.blocks(a_len));
+            let b = Grou::from(rng.blocks(b_len));
+            let expected = mul_reference(a.subset_all().data, b.subset_all().data);
+            assert_eq!(a.toom_3_mul(&b), expected, "{} x {}", a_len, b_len);
+            assert_eq!(a.toom_4_mul(&b), expected, "{} x {}", a_len, b_len);
+            assert_eq!(a.toom_8_mul(&b), expected, "{} x {}", a_len, b_len);
+        }
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        // All-ones operands give the largest values at every point, and
+        // alternating parts make many of them cancel to 0.
+        for length in [9, 32, 120] {
+            let ones = Grou::from(vec![u64::MAX; length]);
+            let alternating = Grou::from(
+                (0..length)
+                    .map(|ind| if (ind / 4) % 2 == 0 { u64::MAX } else { 0 })
+                    .collect::<Vec<u64>>(),
+            );
+            for (a, b) in [
+                (&ones, &ones),
+                (&alternating, &ones),
+                (&alternating, &alternating),
+            ] {
+                let expected = mul_reference(a.subset_all().data, b.subset_all().data);
+                assert_eq!(a.toom_3_mul(b), expected);
+                assert_eq!(a.toom_4_mul(b), expected);
+                assert_eq!(a.toom_8_mul(b), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_scheme() {
+        // Karatsuba is Toom-2 at 0, 1 and infinity, and -1 works as well as 1.
+        let karatsuba = ToomScheme::new(2, &[(0, 1), (-1, 1), (1, 0)]);
+        let toom_3 = ToomScheme::new(3, &[(0, 1), (1, 1), (-1, 1), (-2, 1), (1, 0)]);
+        assert_eq!(toom_3.parts(), 3);
+
+        let mut rng = Lcg(5);
+        let a = Grou::from(rng.blocks(45));
+        let b = Grou::from(rng.blocks(38));
+        let expected = mul_reference(a.subset_all().data, b.subset_all().data);
+        assert_eq!(karatsuba.mul(&a, &b), expected);
+        assert_eq!(toom_3.mul(&a, &b.subset_all()), expected);
+        assert_eq!(toom_3.mul(&a, &Grou::from(0)), Grou::from(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Evaluation points are not distinct")]
+    fn test_repeated_point() {
+        ToomScheme::new(2, &[(0, 1), (1, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn test_grou_mul_uses_toom() {
+        let mut rng = Lcg(9);
+        for length in [TOOM_4_THRESHOLD, TOOM_8_THRESHOLD + 13] {
+            let a = Grou::from(rng.blocks(length));
+            let b = Grou::from(rng.blocks(length - 7));
+            let expected = mul_reference(a.subset_all().data, b.subset_all().data);
+            assert_eq!(&a * &b, expected);
+        }
+    }
+}